@@ -0,0 +1,48 @@
+// Benchmarks for parsing and writing whole cities, run with `cargo bench`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use sc2mpz::{BitFlags, Budget, City};
+
+const CITY: &[u8] = include_bytes!("../fuzz/corpus/city/seed_buildings.sc2");
+const MISC_LEN: usize = 4800;
+
+fn parse_city(c: &mut Criterion) {
+    c.bench_function("City::from_bytes", |b| {
+        b.iter(|| City::from_bytes(black_box(CITY)).expect("seed city should parse"))
+    });
+}
+
+fn write_city(c: &mut Criterion) {
+    let city = City::from_bytes(CITY).expect("seed city should parse");
+
+    c.bench_function("City::write_city_to_bytes", |b| {
+        b.iter(|| black_box(&city).write_city_to_bytes())
+    });
+}
+
+fn parse_bit_flags(c: &mut Criterion) {
+    c.bench_function("BitFlags::from (all tiles)", |b| {
+        b.iter(|| {
+            for flags in 0..(128 * 128) {
+                black_box(BitFlags::from(black_box(flags as u8)));
+            }
+        })
+    });
+}
+
+fn parse_budget(c: &mut Criterion) {
+    let misc = vec![0xA5; MISC_LEN];
+
+    c.bench_function("Budget::from_misc_data", |b| {
+        b.iter(|| Budget::from_misc_data(black_box(&misc)))
+    });
+}
+
+criterion_group!(
+    benches,
+    parse_city,
+    write_city,
+    parse_bit_flags,
+    parse_budget
+);
+criterion_main!(benches);
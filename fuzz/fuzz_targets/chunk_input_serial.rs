@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sc2mpz::open_city_2k::{chunk_input_serial, sc2_uncompress_input};
+
+fuzz_target!(|data: &[u8]| {
+    for input_type in ["sc2", "mif"] {
+        if let Ok(chunk_list) = chunk_input_serial(data, input_type) {
+            let _ = sc2_uncompress_input(chunk_list, input_type);
+        }
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sc2mpz::City;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(city) = City::from_bytes(data) {
+        // Anything that parses has to be writable and parseable again.
+        assert!(City::from_bytes(&city.write_city_to_bytes()).is_ok());
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sc2mpz::open_city_2k::{compress_rle, uncompress_rle};
+
+fuzz_target!(|data: &[u8]| {
+    let decoded = uncompress_rle(data);
+
+    // Whatever comes out of the decoder has to survive a round trip through the encoder.
+    assert_eq!(uncompress_rle(&compress_rle(&decoded)), decoded);
+});
@@ -0,0 +1,17 @@
+//! Parser for Sim City 2000 save files, based on the OpenCity2k project.
+//!
+//! Cities are loaded with [`City::create_city_from_file`], [`City::from_bytes`] or
+//! [`City::from_reader`] and can be written back out as .sc2 files with
+//! [`City::write_city_to_file`].
+
+pub mod open_city_2k;
+
+pub use open_city_2k::{
+    BitFlagLayers, BitFlags, Bond, BondSummary, Budget, BudgetCategory, BudgetKind, BudgetSummary,
+    BuildableBuilding, Building, ChunkList, City, CityAge, CityStatus, Compass, Corners, Date,
+    Direction, GameLevel, GameMode, GameSpeed, Graph, Grid, Invention, Inventions, Minimap, Misc,
+    NeighbourCity, Neighbours, NetworkPiece, NewsEntry, Newspapers, Ordinances, PaperEntry,
+    ParseError, RawChunk, Side, Slope, Terrain, Thing, Tile, TileLayers, Underground,
+    UndergroundNetwork, WaterKind, Zone, Zoom, BOND_AMOUNT, DAYS_PER_MONTH, FORMAT_VERSION,
+    INVENTION_NAMES, MONTHS_PER_YEAR,
+};
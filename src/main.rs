@@ -1,35 +1,196 @@
-mod open_city_2k;
-
 use std::ffi::OsStr;
 use std::fs;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
 
-use anyhow::Result;
-use clap::Parser;
-use flate2::{write::ZlibEncoder, Compression};
-use log::info;
-use open_city_2k::City;
-use rmp_serde::encode;
+use anyhow::{anyhow, Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use flate2::{
+    read::{GzDecoder, ZlibDecoder},
+    write::ZlibEncoder,
+    Compression, GzBuilder,
+};
+use log::{error, info};
+use rmp_serde::{decode, encode};
+use sc2mpz::{City, TileLayers, FORMAT_VERSION};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use simplelog::{
     ColorChoice as LoggerColorChoice, Config as LoggerConfig, LevelFilter, TermLogger, TerminalMode,
 };
 
 #[derive(Parser, Debug)]
-#[clap(version, author, about)]
+#[clap(version, author, about, args_conflicts_with_subcommands = true)]
 struct Args {
+    #[clap(subcommand)]
+    command: Option<Command>,
+
+    #[clap(flatten)]
+    decode: DecodeArgs,
+
+    /// enables debug mode and output
+    #[clap(short, global = true)]
+    debug: bool,
+
+    /// prints the JSON Schema of the output format and exits
+    #[clap(long, exclusive = true, value_enum, num_args = 0..=1, default_missing_value = "city")]
+    print_schema: Option<SchemaKind>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum SchemaKind {
+    /// the whole city
+    City,
+    /// the tile layers written by --layers
+    Layers,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// converts sc2 files into mpz files (default)
+    Decode(DecodeArgs),
+
+    /// converts mpz files back into sc2 files
+    Encode(EncodeArgs),
+}
+
+#[derive(clap::Args, Debug)]
+struct DecodeArgs {
     #[clap(required = true)]
     sc2_file: Vec<PathBuf>,
 
     #[clap(short, long)]
     output: Option<PathBuf>,
 
-    /// enables debug mode and output
-    #[clap(short)]
-    debug: bool,
+    /// format of the output file
+    #[clap(short, long, value_enum, default_value_t = OutputFormat::Mpz)]
+    format: OutputFormat,
+
+    /// compression of the output file, defaults to gzip for mpz and none for all other formats
+    #[clap(short, long, value_enum)]
+    compression: Option<OutputCompression>,
+
+    /// compression level, from 0 (none) to 9 (best)
+    #[clap(short = 'l', long, default_value_t = 9, value_parser = clap::value_parser!(u32).range(0..=9))]
+    compression_level: u32,
+
+    /// writes the tiles as separate 128x128 planes instead of the whole city
+    #[clap(long)]
+    layers: bool,
+
+    /// text file with the game's neighbour city names, one per line, used to name the neighbouring cities
+    #[clap(long)]
+    neighbour_names: Option<PathBuf>,
+}
+
+impl DecodeArgs {
+    fn compression(&self) -> OutputCompression {
+        self.compression
+            .unwrap_or_else(|| self.format.default_compression())
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum OutputFormat {
+    /// gzip compressed MessagePack
+    Mpz,
+    /// MessagePack
+    Msgpack,
+    /// JSON
+    Json,
+    /// indented JSON
+    JsonPretty,
+    /// CBOR
+    Cbor,
+}
+
+impl OutputFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::Mpz => "mpz",
+            Self::Msgpack => "msgpack",
+            Self::Json | Self::JsonPretty => "json",
+            Self::Cbor => "cbor",
+        }
+    }
+
+    /**
+     * Guesses the format of a serialized city from its file name, ignoring a trailing compression extension.
+     * Args:
+     *      file_name: Name of the serialized city.
+     * Returns:
+     *      Detected format, mpz if the extension isn't known.
+     */
+    fn from_file_name(file_name: &str) -> Self {
+        let file_name = OutputCompression::strip_extension(file_name);
+
+        match file_name.rsplit_once('.').map(|(_, extension)| extension) {
+            Some("msgpack") => Self::Msgpack,
+            Some("json") => Self::Json,
+            Some("cbor") => Self::Cbor,
+            _ => Self::Mpz,
+        }
+    }
+
+    fn default_compression(&self) -> OutputCompression {
+        match self {
+            Self::Mpz => OutputCompression::Gzip,
+            _ => OutputCompression::None,
+        }
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum OutputCompression {
+    Zlib,
+    Gzip,
+    None,
+}
+
+impl OutputCompression {
+    fn extension(&self) -> Option<&'static str> {
+        match self {
+            Self::Zlib => Some("zz"),
+            Self::Gzip => Some("gz"),
+            Self::None => None,
+        }
+    }
+
+    /// Removes a trailing .zz or .gz extension from a file name.
+    fn strip_extension(file_name: &str) -> &str {
+        [Self::Zlib, Self::Gzip]
+            .iter()
+            .filter_map(|compression| compression.extension())
+            .find_map(|extension| file_name.strip_suffix(&format!(".{extension}")))
+            .unwrap_or(file_name)
+    }
+}
+
+#[derive(clap::Args, Debug)]
+struct EncodeArgs {
+    #[clap(required = true)]
+    mpz_file: Vec<PathBuf>,
+
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+
+    /// format of the input files, detected from their extension by default
+    #[clap(short, long, value_enum)]
+    format: Option<OutputFormat>,
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
+
+    if let Some(schema_kind) = args.print_schema {
+        let schema = match schema_kind {
+            SchemaKind::City => City::json_schema(),
+            SchemaKind::Layers => TileLayers::json_schema(),
+        };
+
+        println!("{}", serde_json::to_string_pretty(&schema)?);
+        return Ok(());
+    }
+
     let log_level = if args.debug {
         LevelFilter::Debug
     } else {
@@ -43,51 +204,252 @@ fn main() -> Result<()> {
         LoggerColorChoice::Auto,
     )?;
 
-    for path in args.sc2_file {
-        process_file(&path, args.output.as_ref())?;
+    let failed = match args.command {
+        Some(Command::Encode(encode_args)) => process_all(encode_args.mpz_file.clone(), |path| {
+            process_mpz_file(path, &encode_args)
+        }),
+
+        Some(Command::Decode(decode_args)) => decode_all(decode_args),
+
+        None => decode_all(args.decode),
+    };
+
+    if failed > 0 {
+        return Err(anyhow!("{failed} file(s) could not be converted"));
     }
 
     Ok(())
 }
 
-fn get_target_filename(path: &Path) -> String {
-    format!(
-        "{}.mpz",
-        path.file_name()
-            .unwrap_or_else(|| OsStr::new("city.sc2"))
-            .to_string_lossy()
-    )
+/// Converts every input file, logging failures instead of aborting. Returns the number of failed files.
+fn process_all(inputs: Vec<PathBuf>, process: impl Fn(&PathBuf) -> Result<()>) -> usize {
+    let mut failed = 0;
+
+    for path in inputs {
+        if let Err(err) = process(&path) {
+            error!("{:#}", err);
+            failed += 1;
+        }
+    }
+
+    failed
+}
+
+fn decode_all(args: DecodeArgs) -> usize {
+    process_all(args.sc2_file.clone(), |path| process_file(path, &args))
+}
+
+fn get_target_filename(path: &Path, args: &DecodeArgs) -> String {
+    let format = args.format;
+    let compression = args.compression();
+    let file_name = path
+        .file_name()
+        .unwrap_or_else(|| OsStr::new("city.sc2"))
+        .to_string_lossy();
+    let file_name = if args.layers {
+        format!("{file_name}.layers")
+    } else {
+        file_name.into_owned()
+    };
+
+    // mpz files are always compressed, so the extension already says as much.
+    match (format, compression.extension()) {
+        (OutputFormat::Mpz, _) | (_, None) => format!("{}.{}", file_name, format.extension()),
+        (_, Some(compression)) => {
+            format!("{}.{}.{}", file_name, format.extension(), compression)
+        }
+    }
+}
+
+fn get_sc2_target_filename(path: &Path, format: OutputFormat) -> String {
+    let file_name = path
+        .file_name()
+        .unwrap_or_else(|| OsStr::new("city.sc2.mpz"))
+        .to_string_lossy();
+    let file_name = OutputCompression::strip_extension(&file_name);
+
+    match file_name.strip_suffix(&format!(".{}", format.extension())) {
+        Some(sc2_name) if sc2_name.ends_with(".sc2") => sc2_name.to_owned(),
+        Some(city_name) => format!("{city_name}.sc2"),
+        None => format!("{file_name}.sc2"),
+    }
 }
 
-fn process_file(input: &PathBuf, output: Option<&PathBuf>) -> Result<()> {
-    let input = fs::canonicalize(input)?;
-    let output = match output {
+fn get_output_path(input: &Path, output: Option<&PathBuf>, target_filename: String) -> PathBuf {
+    match output {
         Some(path) => {
             let mut out = path.to_owned();
 
             if out.file_name().is_none() {
-                out.set_file_name(get_target_filename(&input));
+                out.set_file_name(target_filename);
             }
 
             out
         }
 
         None => {
-            let mut path = input.clone();
+            let mut path = input.to_owned();
 
-            path.set_file_name(get_target_filename(&input));
+            path.set_file_name(target_filename);
             path
         }
-    };
+    }
+}
+
+fn process_file(input: &PathBuf, args: &DecodeArgs) -> Result<()> {
+    let input = fs::canonicalize(input)
+        .with_context(|| format!("failed to open {}", input.to_string_lossy()))?;
+    let target_filename = get_target_filename(&input, args);
+    let output = get_output_path(&input, args.output.as_ref(), target_filename);
 
-    let city = City::create_city_from_file(&input)?;
-    let out_file = fs::File::create(&output)?;
+    let mut city = City::create_city_from_file(&input)
+        .with_context(|| format!("failed to parse {}", input.to_string_lossy()))?;
 
-    let mut compress = ZlibEncoder::new(out_file, Compression::best());
+    if let Some(names_path) = &args.neighbour_names {
+        let names = fs::read_to_string(names_path)
+            .with_context(|| format!("failed to read {}", names_path.to_string_lossy()))?;
+
+        city.resolve_neighbour_names(&names.lines().collect::<Vec<_>>());
+    }
 
     info!("writing city to {}...", output.to_string_lossy());
-    //to_writer(out_file, &city)?;
-    encode::write_named(&mut compress, &city)?;
+
+    if args.layers {
+        write_output(&input, &output, &city.tile_layers(), args)?;
+    } else {
+        write_output(&input, &output, &city, args)?;
+    }
+
+    info!("done!");
+    Ok(())
+}
+
+fn write_output(
+    input: &Path,
+    output: &Path,
+    value: &impl Serialize,
+    args: &DecodeArgs,
+) -> Result<()> {
+    let out_file = fs::File::create(output)?;
+    let level = Compression::new(args.compression_level);
+
+    match args.compression() {
+        OutputCompression::Zlib => {
+            let mut compress = ZlibEncoder::new(out_file, level);
+
+            serialize(&mut compress, value, args.format)?;
+            compress.finish()?;
+        }
+
+        OutputCompression::Gzip => {
+            let original_filename = input
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let mut compress = GzBuilder::new()
+                .filename(original_filename)
+                .write(out_file, level);
+
+            serialize(&mut compress, value, args.format)?;
+            compress.finish()?;
+        }
+
+        OutputCompression::None => {
+            let mut writer = BufWriter::new(out_file);
+
+            serialize(&mut writer, value, args.format)?;
+            writer.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+fn serialize(writer: &mut impl Write, value: &impl Serialize, format: OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Mpz | OutputFormat::Msgpack => encode::write_named(writer, value)?,
+        OutputFormat::Json => serde_json::to_writer(writer, value)?,
+        OutputFormat::JsonPretty => serde_json::to_writer_pretty(writer, value)?,
+        OutputFormat::Cbor => ciborium::ser::into_writer(value, writer)?,
+    }
+
+    Ok(())
+}
+
+/// The format version of a serialized city, read on its own so files of other versions can be rejected before
+/// their layout fails to deserialize.
+#[derive(Deserialize)]
+struct FormatVersion {
+    #[serde(default)]
+    format_version: u32,
+}
+
+/// Reads a value from uncompressed data in the given format, the counterpart of `serialize`.
+fn deserialize<T: DeserializeOwned>(data: &[u8], format: OutputFormat) -> Result<T> {
+    Ok(match format {
+        OutputFormat::Mpz | OutputFormat::Msgpack => decode::from_slice(data)?,
+        OutputFormat::Json | OutputFormat::JsonPretty => serde_json::from_slice(data)?,
+        OutputFormat::Cbor => ciborium::de::from_reader(data)?,
+    })
+}
+
+/// Magic bytes at the start of every gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Checks for a zlib header: deflate with a window of at most 32K and a valid header checksum.
+fn is_zlib_header(header: &[u8]) -> bool {
+    match header {
+        [cmf, flg, ..] => {
+            cmf & 0x0f == 8 && cmf >> 4 <= 7 && (u16::from(*cmf) << 8 | u16::from(*flg)) % 31 == 0
+        }
+        _ => false,
+    }
+}
+
+/// Wraps an input in the decoder matching its compression, which can be gzip, zlib or none at all.
+fn open_decompressed(input: impl Read + 'static) -> Result<Box<dyn Read>> {
+    let mut reader = BufReader::new(input);
+    let header = reader.fill_buf()?;
+
+    if header.starts_with(&GZIP_MAGIC) {
+        Ok(Box::new(GzDecoder::new(reader)))
+    } else if is_zlib_header(header) {
+        Ok(Box::new(ZlibDecoder::new(reader)))
+    } else {
+        Ok(Box::new(reader))
+    }
+}
+
+fn process_mpz_file(input: &PathBuf, args: &EncodeArgs) -> Result<()> {
+    let input = fs::canonicalize(input)
+        .with_context(|| format!("failed to open {}", input.to_string_lossy()))?;
+    let format = args.format.unwrap_or_else(|| {
+        OutputFormat::from_file_name(&input.file_name().unwrap_or_default().to_string_lossy())
+    });
+    let output = get_output_path(
+        &input,
+        args.output.as_ref(),
+        get_sc2_target_filename(&input, format),
+    );
+
+    info!("reading city from {}...", input.to_string_lossy());
+    let in_file = fs::File::open(&input)?;
+    let mut serialized_city = vec![];
+    open_decompressed(in_file)?.read_to_end(&mut serialized_city)?;
+
+    let FormatVersion { format_version } = deserialize(&serialized_city, format)?;
+    if format_version != FORMAT_VERSION {
+        return Err(anyhow!(
+            "{} uses format version {}, but only version {} can be converted back, decode the original .sc2 file again",
+            input.to_string_lossy(),
+            format_version,
+            FORMAT_VERSION
+        ));
+    }
+
+    let city: City = deserialize(&serialized_city, format)?;
+
+    city.write_city_to_file(&output)?;
 
     info!("done!");
     Ok(())
@@ -1,20 +1,15 @@
 use std::fmt::Display;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
 
-use super::sc_util::{int_to_bitstring, parse_bitstring};
-use serde::Serialize;
-
-fn bit_as_bool(chars: &str, index: usize) -> bool {
-    chars
-        .chars()
-        .nth(index)
-        .expect("should have bit 0")
-        .to_digit(2)
-        .expect("digit")
-        != 0
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const fn bit_as_bool(flags: u8, bit: u8) -> bool {
+    flags & (1 << bit) != 0
 }
 
 /// Stores the bit flags and implements str() and int().
-#[derive(Debug, Serialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct BitFlags {
     powerable: bool,
     powered: bool,
@@ -26,36 +21,32 @@ pub struct BitFlags {
     salt: bool,
 }
 
-/// Returns the integer corresponding to the flags.
-#[allow(dead_code)]
 impl BitFlags {
-    fn to_u32(&self) -> u32 {
-        parse_bitstring(&self.to_string())
-    }
+    pub const NONE: Self = Self::from_byte(0);
+    pub const POWERABLE: Self = Self::from_byte(1 << 7);
+    pub const POWERED: Self = Self::from_byte(1 << 6);
+    pub const PIPED: Self = Self::from_byte(1 << 5);
+    pub const WATERED: Self = Self::from_byte(1 << 4);
+    pub const XVAL: Self = Self::from_byte(1 << 3);
+    pub const WATER: Self = Self::from_byte(1 << 2);
+    pub const ROTATE: Self = Self::from_byte(1 << 1);
+    pub const SALT: Self = Self::from_byte(1);
+    pub const ALL: Self = Self::from_byte(u8::MAX);
 
     /**
-     * Converts this bitflags to a bytes.
-     * Returns:
-     *      A single, big endian byte representation of the bitflags.
+     * Parses the flags of a tile.
+     * Args:
+     *      flags (int): XBIT byte of the tile.
      */
-    fn to_byte(&self) {
-        unimplemented!("to_byte is not implemented!");
-    }
-}
-
-impl From<u8> for BitFlags {
-    fn from(flags: u8) -> Self {
-        let _flags_string = int_to_bitstring(flags as u32, 8);
-        let _flags = _flags_string.as_str();
-
-        let powerable = bit_as_bool(_flags, 0); // Is this a tile that needs power?
-        let powered = bit_as_bool(_flags, 1); // Is this tile recieving power?
-        let piped = bit_as_bool(_flags, 2); // Does this tile have pipes underneath it?
-        let watered = bit_as_bool(_flags, 3); // Is this tile recieving water?
-        let xval = bit_as_bool(_flags, 4); // Land value of this tile
-        let water = bit_as_bool(_flags, 5); // Is this tile covered in water?
-        let rotate = bit_as_bool(_flags, 6); // Should this tile be rotated?
-        let salt = bit_as_bool(_flags, 7); // Is this tile salt water?
+    pub const fn from_byte(flags: u8) -> Self {
+        let powerable = bit_as_bool(flags, 7); // Is this a tile that needs power?
+        let powered = bit_as_bool(flags, 6); // Is this tile recieving power?
+        let piped = bit_as_bool(flags, 5); // Does this tile have pipes underneath it?
+        let watered = bit_as_bool(flags, 4); // Is this tile recieving water?
+        let xval = bit_as_bool(flags, 3); // Land value of this tile
+        let water = bit_as_bool(flags, 2); // Is this tile covered in water?
+        let rotate = bit_as_bool(flags, 1); // Should this tile be rotated?
+        let salt = bit_as_bool(flags, 0); // Is this tile salt water?
 
         Self {
             powerable,
@@ -68,6 +59,131 @@ impl From<u8> for BitFlags {
             salt,
         }
     }
+
+    pub fn powerable(&self) -> bool {
+        self.powerable
+    }
+
+    pub fn powered(&self) -> bool {
+        self.powered
+    }
+
+    pub fn piped(&self) -> bool {
+        self.piped
+    }
+
+    pub fn watered(&self) -> bool {
+        self.watered
+    }
+
+    pub fn xval(&self) -> bool {
+        self.xval
+    }
+
+    pub fn water(&self) -> bool {
+        self.water
+    }
+
+    pub fn rotate(&self) -> bool {
+        self.rotate
+    }
+
+    pub fn salt(&self) -> bool {
+        self.salt
+    }
+
+    /**
+     * Converts this bitflags to a bytes.
+     * Returns:
+     *      A single, big endian byte representation of the bitflags.
+     */
+    pub const fn to_byte(&self) -> u8 {
+        (self.powerable as u8) << 7
+            | (self.powered as u8) << 6
+            | (self.piped as u8) << 5
+            | (self.watered as u8) << 4
+            | (self.xval as u8) << 3
+            | (self.water as u8) << 2
+            | (self.rotate as u8) << 1
+            | self.salt as u8
+    }
+
+    /// Returns the integer corresponding to the flags.
+    pub const fn to_u32(&self) -> u32 {
+        self.to_byte() as u32
+    }
+
+    /// Returns true if all flags of other are set.
+    pub fn contains(&self, other: Self) -> bool {
+        *self & other == other
+    }
+
+    /// Returns true if any flag of other is set.
+    pub fn intersects(&self, other: Self) -> bool {
+        *self & other != Self::NONE
+    }
+}
+
+impl From<u8> for BitFlags {
+    fn from(flags: u8) -> Self {
+        Self::from_byte(flags)
+    }
+}
+
+impl From<BitFlags> for u8 {
+    fn from(flags: BitFlags) -> Self {
+        flags.to_byte()
+    }
+}
+
+impl BitOr for BitFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self::from_byte(self.to_byte() | rhs.to_byte())
+    }
+}
+
+impl BitAnd for BitFlags {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self::from_byte(self.to_byte() & rhs.to_byte())
+    }
+}
+
+impl BitXor for BitFlags {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self::from_byte(self.to_byte() ^ rhs.to_byte())
+    }
+}
+
+impl Not for BitFlags {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self::from_byte(!self.to_byte())
+    }
+}
+
+impl BitOrAssign for BitFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
+
+impl BitAndAssign for BitFlags {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = *self & rhs;
+    }
+}
+
+impl BitXorAssign for BitFlags {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = *self ^ rhs;
+    }
 }
 
 /// Returns a binary string representing the bitflags.
@@ -95,3 +211,79 @@ impl Display for BitFlags {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Accessor = fn(&BitFlags) -> bool;
+
+    #[test]
+    fn byte_round_trip() {
+        for byte in 0..=u8::MAX {
+            let flags = BitFlags::from_byte(byte);
+
+            assert_eq!(flags.to_byte(), byte);
+            assert_eq!(flags.to_u32(), byte as u32);
+        }
+    }
+
+    #[test]
+    fn constants_match_accessors() {
+        let accessors: [(BitFlags, Accessor); 8] = [
+            (BitFlags::POWERABLE, BitFlags::powerable),
+            (BitFlags::POWERED, BitFlags::powered),
+            (BitFlags::PIPED, BitFlags::piped),
+            (BitFlags::WATERED, BitFlags::watered),
+            (BitFlags::XVAL, BitFlags::xval),
+            (BitFlags::WATER, BitFlags::water),
+            (BitFlags::ROTATE, BitFlags::rotate),
+            (BitFlags::SALT, BitFlags::salt),
+        ];
+
+        for (index, (constant, _)) in accessors.iter().enumerate() {
+            for (other_index, (_, accessor)) in accessors.iter().enumerate() {
+                assert_eq!(accessor(constant), index == other_index);
+            }
+        }
+
+        assert!(accessors
+            .iter()
+            .all(|(_, accessor)| !accessor(&BitFlags::NONE)));
+        assert!(accessors
+            .iter()
+            .all(|(_, accessor)| accessor(&BitFlags::ALL)));
+    }
+
+    #[test]
+    fn operators_match_bytes() {
+        for a in 0..=u8::MAX {
+            let flags_a = BitFlags::from_byte(a);
+
+            assert_eq!((!flags_a).to_byte(), !a);
+
+            for b in 0..=u8::MAX {
+                let flags_b = BitFlags::from_byte(b);
+
+                assert_eq!((flags_a | flags_b).to_byte(), a | b);
+                assert_eq!((flags_a & flags_b).to_byte(), a & b);
+                assert_eq!((flags_a ^ flags_b).to_byte(), a ^ b);
+
+                let mut or_assigned = flags_a;
+                or_assigned |= flags_b;
+                assert_eq!(or_assigned.to_byte(), a | b);
+
+                let mut and_assigned = flags_a;
+                and_assigned &= flags_b;
+                assert_eq!(and_assigned.to_byte(), a & b);
+
+                let mut xor_assigned = flags_a;
+                xor_assigned ^= flags_b;
+                assert_eq!(xor_assigned.to_byte(), a ^ b);
+
+                assert_eq!(flags_a.contains(flags_b), a & b == b);
+                assert_eq!(flags_a.intersects(flags_b), a & b != 0);
+            }
+        }
+    }
+}
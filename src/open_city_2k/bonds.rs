@@ -0,0 +1,125 @@
+// Outstanding bonds, stored as 50 interest rates in MISC starting at 0x0610. Empty slots have a rate of 0.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Value of a single bond, the game always issues bonds of $10,000.
+pub const BOND_AMOUNT: i64 = 10_000;
+
+/// An outstanding bond.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Bond {
+    /// Slot of the bond in MISC.
+    pub index: usize,
+    /// Yearly interest rate in percent.
+    pub rate: i32,
+    /// Borrowed amount in dollars.
+    pub amount: i64,
+}
+
+impl Bond {
+    /// Interest paid on this bond every year, in dollars.
+    pub fn annual_interest(&self) -> i64 {
+        self.amount * i64::from(self.rate) / 100
+    }
+}
+
+/// All outstanding bonds of a city and the debt they add up to.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct BondSummary {
+    /// Bonds in the order of their slots, empty slots are skipped.
+    pub bonds: Vec<Bond>,
+    /// TotalBonds as stored in MISC, kept as it is but not trusted. The bond slots are what the game pays interest on.
+    pub total_bonds: i32,
+    /// Sum of the amounts of all bonds, in dollars.
+    pub outstanding_debt: i64,
+    /// Whether total_bonds agrees with outstanding_debt.
+    pub total_bonds_matches: bool,
+    /// Sum of the yearly interest of all bonds, in dollars.
+    pub annual_interest: i64,
+}
+
+impl BondSummary {
+    /**
+     * Collects the outstanding bonds.
+     * Args:
+     *      rates: the 50 bond slots from MISC.
+     *      total_bonds (int): TotalBonds from MISC.
+     */
+    pub fn new(rates: &[i32], total_bonds: i32) -> Self {
+        let bonds: Vec<Bond> = rates
+            .iter()
+            .enumerate()
+            .filter(|(_, rate)| **rate != 0)
+            .map(|(index, rate)| Bond {
+                index,
+                rate: *rate,
+                amount: BOND_AMOUNT,
+            })
+            .collect();
+
+        let outstanding_debt = bonds.iter().map(|bond| bond.amount).sum();
+        let annual_interest = bonds.iter().map(Bond::annual_interest).sum();
+
+        Self {
+            bonds,
+            total_bonds,
+            outstanding_debt,
+            total_bonds_matches: i64::from(total_bonds) == outstanding_debt,
+            annual_interest,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::budget::Budget;
+    use super::super::misc::Misc;
+    use super::super::sc_util::write_int32;
+    use super::*;
+
+    /// MISC with three bonds at 5%, 7% and 9% in slots 0, 1 and 4 and the given TotalBonds.
+    fn misc_with_bonds(total_bonds: i32) -> Vec<u8> {
+        let mut misc = vec![0u8; 4800];
+
+        write_int32(&mut misc, 0x0018, total_bonds);
+        write_int32(&mut misc, 0x0610, 5);
+        write_int32(&mut misc, 0x0614, 7);
+        write_int32(&mut misc, 0x0620, 9);
+
+        misc
+    }
+
+    fn summary(misc: &[u8]) -> BondSummary {
+        BondSummary::new(
+            Budget::from_misc_data(misc).bonds(),
+            Misc::from_misc_data(misc).total_bonds,
+        )
+    }
+
+    #[test]
+    fn total_bonds_matches() {
+        let summary = summary(&misc_with_bonds(30_000));
+
+        assert_eq!(
+            summary
+                .bonds
+                .iter()
+                .map(|bond| bond.index)
+                .collect::<Vec<_>>(),
+            [0, 1, 4]
+        );
+        assert_eq!(summary.outstanding_debt, 3 * BOND_AMOUNT);
+        assert_eq!(summary.annual_interest, 500 + 700 + 900);
+        assert!(summary.total_bonds_matches);
+    }
+
+    #[test]
+    fn total_bonds_mismatch() {
+        let summary = summary(&misc_with_bonds(50_000));
+
+        assert_eq!(summary.total_bonds, 50_000);
+        assert_eq!(summary.outstanding_debt, 3 * BOND_AMOUNT);
+        assert!(!summary.total_bonds_matches);
+    }
+}
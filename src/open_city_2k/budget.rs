@@ -1,43 +1,46 @@
-use super::indexed_write::{IndexedWrite, IndexedWriter};
-use super::sc_util::{bytes_to_int32s, int_to_bitstring, parse_bitstring, parse_uint32};
+use super::budget_summary::{BudgetCategory, BudgetKind, BudgetSummary};
+use super::indexed_write::{IndexedRead, IndexedWrite, IndexedWriter};
+use super::ordinances::Ordinances;
+use super::sc_util::{bytes_to_int32s, int32s_to_bytes, parse_uint32, write_int32};
+use schemars::JsonSchema;
 use serde::ser::SerializeSeq;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
-#[derive(Default, Debug, Serialize)]
+#[derive(Default, Debug, Serialize, Deserialize, JsonSchema)]
 struct SubBudget {
-    current_count: usize,
-    current_funding: usize,
-    unknown: usize,
-    jan_count: usize,
-    jan_funding: usize,
-    feb_count: usize,
-    feb_funding: usize,
-    mar_count: usize,
-    mar_funding: usize,
-    apr_count: usize,
-    apr_funding: usize,
-    may_count: usize,
-    may_funding: usize,
-    jun_count: usize,
-    jun_funding: usize,
-    jul_count: usize,
-    jul_funding: usize,
-    aug_count: usize,
-    aug_funding: usize,
-    sep_count: usize,
-    sep_funding: usize,
-    oct_count: usize,
-    oct_funding: usize,
-    nov_count: usize,
-    nov_funding: usize,
-    dec_count: usize,
-    dec_funding: usize,
+    current_count: i32,
+    current_funding: i32,
+    unknown: i32,
+    jan_count: i32,
+    jan_funding: i32,
+    feb_count: i32,
+    feb_funding: i32,
+    mar_count: i32,
+    mar_funding: i32,
+    apr_count: i32,
+    apr_funding: i32,
+    may_count: i32,
+    may_funding: i32,
+    jun_count: i32,
+    jun_funding: i32,
+    jul_count: i32,
+    jul_funding: i32,
+    aug_count: i32,
+    aug_funding: i32,
+    sep_count: i32,
+    sep_funding: i32,
+    oct_count: i32,
+    oct_funding: i32,
+    nov_count: i32,
+    nov_funding: i32,
+    dec_count: i32,
+    dec_funding: i32,
 }
 
-impl IndexedWrite<usize> for SubBudget {
-    fn write(&mut self, index: usize, value: usize) {
+impl IndexedWrite<i32> for SubBudget {
+    fn write(&mut self, index: usize, value: i32) {
         match index {
             0 => self.current_count = value,
             1 => self.current_funding = value,
@@ -76,7 +79,60 @@ impl IndexedWrite<usize> for SubBudget {
     }
 }
 
-/// Ordinances isn't really handled properly yet here, but it's here for now.
+impl IndexedRead<i32> for SubBudget {
+    fn read(&self, index: usize) -> i32 {
+        match index {
+            0 => self.current_count,
+            1 => self.current_funding,
+            2 => self.unknown,
+            3 => self.jan_count,
+            4 => self.jan_funding,
+            5 => self.feb_count,
+            6 => self.feb_funding,
+            7 => self.mar_count,
+            8 => self.mar_funding,
+            9 => self.apr_count,
+            10 => self.apr_funding,
+            11 => self.may_count,
+            12 => self.may_funding,
+            13 => self.jun_count,
+            14 => self.jun_funding,
+            15 => self.jul_count,
+            16 => self.jul_funding,
+            17 => self.aug_count,
+            18 => self.aug_funding,
+            19 => self.sep_count,
+            20 => self.sep_funding,
+            21 => self.oct_count,
+            22 => self.oct_funding,
+            23 => self.nov_count,
+            24 => self.nov_funding,
+            25 => self.dec_count,
+            26 => self.dec_funding,
+
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+impl SubBudget {
+    /// Count and funding of the current month.
+    fn current(&self) -> (i32, i32) {
+        (self.current_count, self.current_funding)
+    }
+
+    /// Count and funding pairs of every month, January first.
+    fn monthly(&self) -> Vec<(i32, i32)> {
+        (0..12)
+            .map(|month| {
+                let index = 3 + month * 2;
+
+                (self.read(index), self.read(index + 1))
+            })
+            .collect()
+    }
+}
+
 #[derive(EnumIter, Copy, Clone)]
 enum SubBudgetIndices {
     Residential,
@@ -97,6 +153,40 @@ enum SubBudgetIndices {
     Tunnel,
 }
 
+impl SubBudgetIndices {
+    fn name(self) -> &'static str {
+        match self {
+            SubBudgetIndices::Residential => "Residential",
+            SubBudgetIndices::Commercial => "Commercial",
+            SubBudgetIndices::Industrial => "Industrial",
+            SubBudgetIndices::Ordinances => "Ordinances",
+            SubBudgetIndices::Bonds => "Bonds",
+            SubBudgetIndices::Police => "Police",
+            SubBudgetIndices::Fire => "Fire",
+            SubBudgetIndices::Health => "Health",
+            SubBudgetIndices::Schools => "Schools",
+            SubBudgetIndices::Colleges => "Colleges",
+            SubBudgetIndices::Road => "Road",
+            SubBudgetIndices::Hiway => "Hiway",
+            SubBudgetIndices::Bridge => "Bridge",
+            SubBudgetIndices::Rail => "Rail",
+            SubBudgetIndices::Subway => "Subway",
+            SubBudgetIndices::Tunnel => "Tunnel",
+        }
+    }
+
+    /// Taxes bring money in, bond interest, services and transportation cost money. Ordinances can do either.
+    fn kind(self) -> BudgetKind {
+        match self {
+            SubBudgetIndices::Residential
+            | SubBudgetIndices::Commercial
+            | SubBudgetIndices::Industrial => BudgetKind::Income,
+            SubBudgetIndices::Ordinances => BudgetKind::Signed,
+            _ => BudgetKind::Expense,
+        }
+    }
+}
+
 impl From<SubBudgetIndices> for u16 {
     fn from(value: SubBudgetIndices) -> u16 {
         match value {
@@ -120,7 +210,7 @@ impl From<SubBudgetIndices> for u16 {
     }
 }
 
-#[derive(Default, Debug, Serialize)]
+#[derive(Default, Debug, Serialize, Deserialize, JsonSchema)]
 struct BudgetItems {
     residential: SubBudget,
     commercial: SubBudget,
@@ -141,10 +231,32 @@ struct BudgetItems {
 }
 
 trait EnumMap<K, V> {
+    fn get(&self, key: K) -> &V;
     fn get_mut(&mut self, key: K) -> &mut V;
 }
 
 impl EnumMap<SubBudgetIndices, SubBudget> for BudgetItems {
+    fn get(&self, key: SubBudgetIndices) -> &SubBudget {
+        match key {
+            SubBudgetIndices::Residential => &self.residential,
+            SubBudgetIndices::Commercial => &self.commercial,
+            SubBudgetIndices::Industrial => &self.industrial,
+            SubBudgetIndices::Ordinances => &self.ordinances,
+            SubBudgetIndices::Bonds => &self.bonds,
+            SubBudgetIndices::Police => &self.police,
+            SubBudgetIndices::Fire => &self.fire,
+            SubBudgetIndices::Health => &self.health,
+            SubBudgetIndices::Schools => &self.schools,
+            SubBudgetIndices::Colleges => &self.colleges,
+            SubBudgetIndices::Road => &self.road,
+            SubBudgetIndices::Hiway => &self.hiway,
+            SubBudgetIndices::Bridge => &self.bridge,
+            SubBudgetIndices::Rail => &self.rail,
+            SubBudgetIndices::Subway => &self.subway,
+            SubBudgetIndices::Tunnel => &self.tunnel,
+        }
+    }
+
     fn get_mut(&mut self, key: SubBudgetIndices) -> &mut SubBudget {
         match key {
             SubBudgetIndices::Residential => &mut self.residential,
@@ -167,12 +279,16 @@ impl EnumMap<SubBudgetIndices, SubBudget> for BudgetItems {
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct Budget {
     budget_items: BudgetItems,
-    #[serde(serialize_with = "serialize_array")]
+    #[serde(
+        serialize_with = "serialize_array",
+        deserialize_with = "deserialize_array"
+    )]
+    #[schemars(with = "Vec<i32>", length(equal = 50))]
     bonds: [i32; 50],
-    ordinance_flags: [u32; 32],
+    ordinances: Ordinances,
 }
 
 impl Budget {
@@ -187,14 +303,7 @@ impl Budget {
             .try_into()
             .expect("we should have 4 bytes");
 
-        let mut ordinance_flags = [u32::default(); 32];
-
-        for (index, char) in int_to_bitstring(parse_uint32(ordinance_raw), 0)
-            .chars()
-            .enumerate()
-        {
-            ordinance_flags[index] = parse_bitstring(&char.to_string());
-        }
+        let ordinances = Ordinances::from_bits(parse_uint32(ordinance_raw));
 
         // bonds
         let start_offset = 0x0610;
@@ -221,14 +330,75 @@ impl Budget {
             let mut sub_budget_writer = IndexedWriter::new(sub_budget);
 
             for item in chunk_data.iter().take(SubBudget::field_len()) {
-                sub_budget_writer.set_next(*item as usize);
+                sub_budget_writer.set_next(*item);
             }
         }
 
         Self {
             budget_items,
             bonds,
-            ordinance_flags,
+            ordinances,
+        }
+    }
+
+    pub fn bonds(&self) -> &[i32; 50] {
+        &self.bonds
+    }
+
+    pub fn ordinances(&self) -> &Ordinances {
+        &self.ordinances
+    }
+
+    /**
+     * Sums up the monthly budget data.
+     * Args:
+     *      funds (int): TotalFunds from MISC, the starting point of the projection.
+     *      current_month (int): month the city is in, 1 for January.
+     * Returns:
+     *      Monthly series and yearly totals per category, income and expenses, and the projected funds.
+     */
+    pub fn summary(&self, funds: i32, current_month: u8) -> BudgetSummary {
+        let categories = SubBudgetIndices::iter()
+            .map(|name| {
+                let sub_budget = self.budget_items.get(name);
+
+                BudgetCategory::new(
+                    name.name(),
+                    name.kind(),
+                    sub_budget.current(),
+                    &sub_budget.monthly(),
+                )
+            })
+            .collect();
+
+        BudgetSummary::new(categories, funds, current_month)
+    }
+
+    /**
+     * Writes the budget data back into its segments of MISC, the inverse of from_misc_data().
+     * Args:
+     *      raw_misc_data (bytes): MISC segment to write into.
+     */
+    pub fn write_misc_data(&self, raw_misc_data: &mut [u8]) {
+        // Ordinances
+        write_int32(raw_misc_data, 0x0FA0, self.ordinances.to_bits() as i32);
+
+        // bonds
+        let start_offset = 0x0610;
+        let bonds_end = start_offset + 50 * 4;
+
+        raw_misc_data[start_offset..bonds_end].copy_from_slice(&int32s_to_bytes(&self.bonds));
+
+        // various sub-budgets
+        for name in SubBudgetIndices::iter() {
+            let start_offset: u16 = name.into();
+            let sub_budget = self.budget_items.get(name);
+
+            for index in 0..SubBudget::field_len() {
+                let offset = start_offset as usize + index * 4;
+
+                write_int32(raw_misc_data, offset, sub_budget.read(index));
+            }
         }
     }
 }
@@ -249,3 +419,16 @@ where
 
     sequence.end()
 }
+
+fn deserialize_array<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    let collection = Vec::<T>::deserialize(deserializer)?;
+    let len = collection.len();
+
+    collection
+        .try_into()
+        .map_err(|_| serde::de::Error::invalid_length(len, &format!("{N} items").as_str()))
+}
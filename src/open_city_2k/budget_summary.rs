@@ -0,0 +1,218 @@
+// Totals and projections over the monthly budget data from MISC.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Which side of the budget window a category is shown on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum BudgetKind {
+    /// Property taxes.
+    Income,
+    /// Bond interest, city services and transportation.
+    Expense,
+    /// Ordinances, which bring money in when positive and cost money when negative.
+    Signed,
+}
+
+impl BudgetKind {
+    /**
+     * Splits a funding value into its income and expense part.
+     * Args:
+     *      funding (int): funding of a category in dollars.
+     * Returns:
+     *      Income and expense, both positive.
+     */
+    fn split(self, funding: i64) -> (i64, i64) {
+        match self {
+            Self::Income => (funding, 0),
+            Self::Expense => (0, funding),
+            Self::Signed => (funding.max(0), (-funding).max(0)),
+        }
+    }
+}
+
+/// Monthly figures of a single budget category.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BudgetCategory {
+    /// Name of the category, as in the budget window.
+    pub name: String,
+    pub kind: BudgetKind,
+    /// Count for the current month, e.g. the number of stations or the taxed population.
+    pub current_count: i32,
+    /// Funding for the current month, in dollars.
+    pub current_funding: i32,
+    /// Count for every month, January first.
+    pub monthly_count: Vec<i32>,
+    /// Funding for every month in dollars, January first.
+    pub monthly_funding: Vec<i32>,
+    /// Sum of the monthly funding, in dollars.
+    pub yearly_total: i64,
+}
+
+impl BudgetCategory {
+    /**
+     * Collects the monthly figures of a category.
+     * Args:
+     *      name (str): name of the category.
+     *      kind: whether the category is income or expense.
+     *      current (int, int): count and funding of the current month.
+     *      monthly: count and funding pairs, January first.
+     */
+    pub fn new(name: &str, kind: BudgetKind, current: (i32, i32), monthly: &[(i32, i32)]) -> Self {
+        let monthly_count: Vec<i32> = monthly.iter().map(|(count, _)| *count).collect();
+        let monthly_funding: Vec<i32> = monthly.iter().map(|(_, funding)| *funding).collect();
+        let yearly_total = monthly_funding.iter().copied().map(i64::from).sum();
+
+        Self {
+            name: name.to_string(),
+            kind,
+            current_count: current.0,
+            current_funding: current.1,
+            monthly_count,
+            monthly_funding,
+            yearly_total,
+        }
+    }
+}
+
+/// The budget of a city summed up per category, month and year.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct BudgetSummary {
+    /// All budget categories in the order of the budget window.
+    pub categories: Vec<BudgetCategory>,
+    /// Income of every month, January first.
+    pub monthly_income: Vec<i64>,
+    /// Expenses of every month, January first.
+    pub monthly_expense: Vec<i64>,
+    /// Sum of the yearly totals of all income categories.
+    pub yearly_income: i64,
+    /// Sum of the yearly totals of all expense categories.
+    pub yearly_expense: i64,
+    /// Income minus expenses over the year.
+    pub yearly_net: i64,
+    /// Month the city is in, 1 for January, taken from simCycle in MISC.
+    pub current_month: u8,
+    /// Current funds, TotalFunds from MISC.
+    pub funds: i32,
+    /// Funds at the end of the year, assuming the remaining months average out like the ones up to the current month.
+    pub projected_year_end_funds: i64,
+}
+
+impl BudgetSummary {
+    /**
+     * Sums up the budget categories.
+     * Args:
+     *      categories: all budget categories.
+     *      funds (int): TotalFunds from MISC.
+     *      current_month (int): month the city is in, 1 for January.
+     */
+    pub fn new(categories: Vec<BudgetCategory>, funds: i32, current_month: u8) -> Self {
+        let months = categories
+            .iter()
+            .map(|category| category.monthly_funding.len())
+            .max()
+            .unwrap_or_default();
+
+        let mut monthly_income = vec![0i64; months];
+        let mut monthly_expense = vec![0i64; months];
+
+        for category in &categories {
+            for (month, funding) in category.monthly_funding.iter().enumerate() {
+                let (income, expense) = category.kind.split(i64::from(*funding));
+
+                monthly_income[month] += income;
+                monthly_expense[month] += expense;
+            }
+        }
+
+        let yearly_income: i64 = monthly_income.iter().sum();
+        let yearly_expense: i64 = monthly_expense.iter().sum();
+        let yearly_net = yearly_income - yearly_expense;
+
+        // Months after the current one still hold the figures of last year, so only the months up to the current
+        // one are averaged.
+        let elapsed = usize::from(current_month.max(1)).min(months);
+        let projected_year_end_funds = match elapsed {
+            0 => i64::from(funds),
+            elapsed => {
+                let net: i64 = (0..elapsed)
+                    .map(|month| monthly_income[month] - monthly_expense[month])
+                    .sum();
+                let remaining = (months - elapsed) as i64;
+
+                i64::from(funds) + net * remaining / elapsed as i64
+            }
+        };
+
+        Self {
+            categories,
+            monthly_income,
+            monthly_expense,
+            yearly_income,
+            yearly_expense,
+            yearly_net,
+            current_month,
+            funds,
+            projected_year_end_funds,
+        }
+    }
+
+    /**
+     * Looks up a budget category.
+     * Args:
+     *      name (str): name of the category, as in the budget window.
+     */
+    pub fn category(&self, name: &str) -> Option<&BudgetCategory> {
+        self.categories
+            .iter()
+            .find(|category| category.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn category(name: &str, kind: BudgetKind, funding: [i32; 12]) -> BudgetCategory {
+        let monthly: Vec<(i32, i32)> = funding.iter().map(|funding| (1, *funding)).collect();
+
+        BudgetCategory::new(name, kind, monthly[0], &monthly)
+    }
+
+    #[test]
+    fn signed_ordinances() {
+        let mut ordinances = [0; 12];
+        ordinances[0] = 30;
+        ordinances[1] = -20;
+
+        let summary = BudgetSummary::new(
+            vec![category("Ordinances", BudgetKind::Signed, ordinances)],
+            0,
+            2,
+        );
+
+        assert_eq!(summary.monthly_income[..2], [30, 0]);
+        assert_eq!(summary.monthly_expense[..2], [0, 20]);
+        assert_eq!(summary.yearly_net, 10);
+    }
+
+    #[test]
+    fn projects_from_current_month() {
+        // March, the months after it still hold last year's figures.
+        let summary = BudgetSummary::new(
+            vec![
+                category("Residential", BudgetKind::Income, [100; 12]),
+                category(
+                    "Police",
+                    BudgetKind::Expense,
+                    [40, 40, 40, 900, 900, 900, 900, 900, 900, 900, 900, 900],
+                ),
+            ],
+            1000,
+            3,
+        );
+
+        assert_eq!(summary.current_month, 3);
+        assert_eq!(summary.projected_year_end_funds, 1000 + 60 * 9);
+    }
+}
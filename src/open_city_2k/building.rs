@@ -1,40 +1,56 @@
+use std::borrow::Cow;
 use std::fmt::Display;
 
 use super::buildings;
-use serde::Serialize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct Building {
     pub building_id: u8,
     tile_coords: (usize, usize),
-    name: &'static str,
+    name: Cow<'static, str>,
     size: usize,
 }
 
 impl Building {
-    pub fn new(building_id: u8, coords: (usize, usize)) -> Self {
-        let name = buildings::get_name(&building_id).unwrap_or_else(|_| {
-            panic!(
-                "trying to create bulding with invalid id {:#04x}",
-                building_id
-            )
-        });
-
-        let size = buildings::get_size(&building_id).unwrap_or_else(|_| {
-            panic!(
-                "trying to create bulding with invalid id {:#04x}",
-                building_id
-            )
-        });
+    /**
+     * Creates a building from its id in XBLD.
+     * Args:
+     *      building_id (int): id of the building.
+     *      coords: coordinates of the building's left corner.
+     * Returns:
+     *      The building, or None if the id is not a known building.
+     */
+    pub fn new(building_id: u8, coords: (usize, usize)) -> Option<Self> {
+        let name = buildings::get_name(&building_id).ok()?;
+        let size = buildings::get_size(&building_id).ok()?;
 
         let tile_coords = coords;
 
-        Self {
+        Some(Self {
             building_id,
             tile_coords,
-            name,
+            name: Cow::Borrowed(name),
             size,
-        }
+        })
+    }
+
+    pub fn tile_coords(&self) -> (usize, usize) {
+        self.tile_coords
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Name of the invention that has to be made before this building can be built, if any.
+    pub fn invention(&self) -> Option<&'static str> {
+        buildings::get_invention(&self.building_id).ok().flatten()
     }
 }
 
@@ -25,6 +25,7 @@ use std::ops::Range;
  *          Note that if water = true, this is assumed to be the normal small pipes. Otherwise, use the special tile.
  *      microsim: What microsim applies to this tile (if any?)
  *          Possible Values: city_hall, hospital, police, fire, museum, park, school, stadium, prison, college, zoo, statue, library, bus, rail, wind, hydro, marina, subway, plymouth, forest, darco, launch, dome, mansion.
+ *      invention: Which invention has to be made before this tile can be built? Named as in the inventions from MISC.
  */
 #[allow(dead_code)]
 struct TileAttributes {
@@ -43,6 +44,7 @@ struct TileAttributes {
     maintenance: usize,
     underground: usize,
     microsim: Microsim,
+    invention: Option<&'static str>,
 }
 
 enum TileZone {
@@ -113,6 +115,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x01u8 => TileAttributes {
             name: "Rubble 1",
@@ -130,6 +133,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x02u8 => TileAttributes {
             name: "Rubble 2",
@@ -147,6 +151,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x03u8 => TileAttributes {
             name: "Rubble 3",
@@ -164,6 +169,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x04u8 => TileAttributes {
             name: "Rubble 4",
@@ -181,6 +187,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x05u8 => TileAttributes {
             name: "Radioactive Waste",
@@ -198,6 +205,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x06u8 => TileAttributes {
             name: "Tree",
@@ -215,6 +223,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x07u8 => TileAttributes {
             name: "Couple O Trees",
@@ -232,6 +241,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x08u8 => TileAttributes {
             name: "More Trees",
@@ -249,6 +259,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x09u8 => TileAttributes {
             name: "Morer Trees",
@@ -266,6 +277,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x0Au8 => TileAttributes {
             name: "Even More Trees",
@@ -283,6 +295,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x0Bu8 => TileAttributes {
             name: "Tons O Trees",
@@ -300,6 +313,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x0Cu8 => TileAttributes {
             name: "Veritable Jungle",
@@ -317,6 +331,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x0Du8 => TileAttributes {
             name: "Small Park",
@@ -334,6 +349,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // Power Lines
     0x0Eu8 => TileAttributes {
@@ -352,6 +368,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x0Fu8 => TileAttributes {
             name: "Power Line: Top-Bottom",
@@ -369,6 +386,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x10u8 => TileAttributes {
             name: "Power Line: HighTop-Bottom",
@@ -386,6 +404,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x11u8 => TileAttributes {
             name: "Power Line: Left-HighRight",
@@ -403,6 +422,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x12u8 => TileAttributes {
             name: "Power Line: Top-HighBottom",
@@ -420,6 +440,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x13u8 => TileAttributes {
             name: "Power Line: HighLeft-Right",
@@ -437,6 +458,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x14u8 => TileAttributes {
             name: "Power Line: Bottom-Right",
@@ -454,6 +476,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x15u8 => TileAttributes {
             name: "Power Line: Bottom-Left",
@@ -471,6 +494,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x16u8 => TileAttributes {
             name: "Power Line: Top-Left",
@@ -488,6 +512,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x17u8 => TileAttributes {
             name: "Power Line: Top-Right",
@@ -505,6 +530,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x18u8 => TileAttributes {
             name: "Power Line: Right-Top-Left",
@@ -522,6 +548,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x19u8 => TileAttributes {
             name: "Power Line: Right-Bottom-Left",
@@ -539,6 +566,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x1Au8 => TileAttributes {
             name: "Power Line: Top-Left-Bottom",
@@ -556,6 +584,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x1Bu8 => TileAttributes {
             name: "Power Line: Left-Top-Bottom",
@@ -573,6 +602,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x1Cu8 => TileAttributes {
             name: "Power Line: Left-Top-Bottom-Right",
@@ -590,6 +620,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // Roads
     0x1Du8 => TileAttributes {
@@ -608,6 +639,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x1Eu8 => TileAttributes {
             name: "Road: Top-Bottom",
@@ -625,6 +657,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x1Fu8 => TileAttributes {
             name: "Road: HighTop-Bottom",
@@ -642,6 +675,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x20u8 => TileAttributes {
             name: "Road: Left-HighRight",
@@ -659,6 +693,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x21u8 => TileAttributes {
             name: "Road: Top-HighBottom",
@@ -676,6 +711,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x22u8 => TileAttributes {
             name: "Road: HighLeft-Right",
@@ -693,6 +729,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x23u8 => TileAttributes {
             name: "Road: Bottom-Right",
@@ -710,6 +747,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x24u8 => TileAttributes {
             name: "Road: Bottom-Left",
@@ -727,6 +765,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x25u8 => TileAttributes {
             name: "Road: Top-Left",
@@ -744,6 +783,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x26u8 => TileAttributes {
             name: "Road: Top-Right",
@@ -761,6 +801,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x27u8 => TileAttributes {
             name: "Road: Right-Top-Left",
@@ -778,6 +819,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x28u8 => TileAttributes {
             name: "Road: Right-Bottom-Left",
@@ -795,6 +837,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x29u8 => TileAttributes {
             name: "Road: Top-Left-Bottom",
@@ -812,6 +855,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x2Au8 => TileAttributes {
             name: "Road: Left-Top-Bottom",
@@ -829,6 +873,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x2Bu8 => TileAttributes {
             name: "Road: Left-Top-Bottom-Right",
@@ -846,6 +891,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // Rail
     0x2Cu8 => TileAttributes {
@@ -864,6 +910,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x2Du8 => TileAttributes {
             name: "Rail: Top-Bottom",
@@ -881,6 +928,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x2Eu8 => TileAttributes {
             name: "Rail: HighTop-Bottom",
@@ -898,6 +946,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x2Fu8 => TileAttributes {
             name: "Rail: Left-HighRight",
@@ -915,6 +964,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x30u8 => TileAttributes {
             name: "Rail: Top-HighBottom",
@@ -932,6 +982,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x31u8 => TileAttributes {
             name: "Rail: HighLeft-Right",
@@ -949,6 +1000,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x32u8 => TileAttributes {
             name: "Rail: Bottom-Right",
@@ -966,6 +1018,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x33u8 => TileAttributes {
             name: "Rail: Bottom-Left",
@@ -983,6 +1036,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x34u8 => TileAttributes {
             name: "Rail: Top-Left",
@@ -1000,6 +1054,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x35u8 => TileAttributes {
             name: "Rail: Top-Right",
@@ -1017,6 +1072,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x36u8 => TileAttributes {
             name: "Rail: Right-Top-Left",
@@ -1034,6 +1090,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x37u8 => TileAttributes {
             name: "Rail: Right-Bottom-Left",
@@ -1051,6 +1108,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x38u8 => TileAttributes {
             name: "Rail: Top-Left-Bottom",
@@ -1068,6 +1126,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x39u8 => TileAttributes {
             name: "Rail: Left-Top-Bottom",
@@ -1085,6 +1144,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x3Au8 => TileAttributes {
             name: "Rail: Left-Top-Bottom-Right",
@@ -1102,6 +1162,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x3Bu8 => TileAttributes {
             name: "Rail: HighTop-Bottom",
@@ -1119,6 +1180,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x3Cu8 => TileAttributes {
             name: "Rail: Left-HighRight",
@@ -1136,6 +1198,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x3Du8 => TileAttributes {
             name: "Rail: Top-HighBottom",
@@ -1153,6 +1216,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x3Eu8 => TileAttributes {
             name: "Rail: HighLeft-Right",
@@ -1170,6 +1234,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     //  Tunnels
     0x3Fu8 => TileAttributes {
@@ -1188,6 +1253,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x40u8 => TileAttributes {
             name: "Tunnel: Right",
@@ -1205,6 +1271,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x41u8 => TileAttributes {
             name: "Tunnel: Bottom",
@@ -1222,6 +1289,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x42u8 => TileAttributes {
             name: "Tunnel: Left",
@@ -1239,6 +1307,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // Crossovers
     0x43u8 => TileAttributes {
@@ -1257,6 +1326,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x44u8 => TileAttributes {
             name: "Power:Left-Right, Road:Top-Bottom",
@@ -1274,6 +1344,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x45u8 => TileAttributes {
             name: "Road:Left-Right, Rail:Top-Bottom",
@@ -1291,6 +1362,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x46u8 => TileAttributes {
             name: "Road:Top-Bottom, Rail:Left-Right",
@@ -1308,6 +1380,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x47u8 => TileAttributes {
             name: "Rail:Left-Right, Power:Top-Bottom",
@@ -1325,6 +1398,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x48u8 => TileAttributes {
             name: "Rail:Top-Bottom, Power:Left-Right",
@@ -1342,6 +1416,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // Highways
     0x49u8 => TileAttributes {
@@ -1360,6 +1435,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x4Au8 => TileAttributes {
             name: "Highway: Top-Bottom",
@@ -1377,6 +1453,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     // Highway Crossovers
     0x4Bu8 => TileAttributes {
@@ -1395,6 +1472,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x4Cu8 => TileAttributes {
             name: " Highway:Top-Bottom, Road:Left-Right",
@@ -1412,6 +1490,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x4Du8 => TileAttributes {
             name: "Highway:Left-Right, Rail:Top-Bottom",
@@ -1429,6 +1508,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x4Eu8 => TileAttributes {
             name: "Highway:Top-Bottom, Rail:Left-Right",
@@ -1446,6 +1526,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x4Fu8 => TileAttributes {
             name: "Highway:Top-Bottom, Power:Left-Right",
@@ -1463,6 +1544,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x50u8 => TileAttributes {
             name: "Highway:Left-Right, Power:Top-Bottom",
@@ -1480,6 +1562,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     // Bridges
     0x51u8 => TileAttributes {
@@ -1498,6 +1581,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x52u8 => TileAttributes {
             name: "Suspension Bridge: Middle:Bottom",
@@ -1515,6 +1599,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x53u8 => TileAttributes {
             name: "Suspension Bridge: Center",
@@ -1532,6 +1617,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x54u8 => TileAttributes {
             name: "Suspension Bridge: Middle:Top",
@@ -1549,6 +1635,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x55u8 => TileAttributes {
             name: "Suspension Bridge: Start:Top",
@@ -1566,6 +1653,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x56u8 => TileAttributes {
             name: "Raising Bridge: Tower",
@@ -1583,6 +1671,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x57u8 => TileAttributes {
             name: "Bridge: Pylon",
@@ -1600,6 +1689,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x58u8 => TileAttributes {
             name: "Bridge: Deck",
@@ -1617,6 +1707,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x59u8 => TileAttributes {
             name: "Raising Bridge: Deck:Raised",
@@ -1634,6 +1725,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x5Au8 => TileAttributes {
             name: "Rail Bridge: Pylon",
@@ -1651,6 +1743,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x5Bu8 => TileAttributes {
             name: "Rail Bridge: Deck",
@@ -1668,6 +1761,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x5Cu8 => TileAttributes {
             name: "Raised Power Lines",
@@ -1685,6 +1779,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // Onramps
     0x5Du8 => TileAttributes {
@@ -1703,6 +1798,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x5Eu8 => TileAttributes {
             name: "Onramp: Highway:Top-Road:Right",
@@ -1720,6 +1816,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x5Fu8 => TileAttributes {
             name: "Onramp: Highway:Bottom-Road:Left",
@@ -1737,6 +1834,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x60u8 => TileAttributes {
             name: "Onramp: Highway:Bottom-Road:Right",
@@ -1754,6 +1852,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     // Highways
     0x61u8 => TileAttributes {
@@ -1772,6 +1871,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x62u8 => TileAttributes {
             name: "Highway: Left-HighRight",
@@ -1789,6 +1889,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x63u8 => TileAttributes {
             name: "Highway: Top-HighBottom",
@@ -1806,6 +1907,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x64u8 => TileAttributes {
             name: "Highway: HighLeft-Right",
@@ -1823,6 +1925,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x65u8 => TileAttributes {
             name: "Highway: Bottom-Right",
@@ -1840,6 +1943,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x66u8 => TileAttributes {
             name: "Highway: Bottom-Left",
@@ -1857,6 +1961,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x67u8 => TileAttributes {
             name: "Highway: Top-Left",
@@ -1874,6 +1979,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x68u8 => TileAttributes {
             name: "Highway: Top-Right",
@@ -1891,6 +1997,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x69u8 => TileAttributes {
             name: "Highway: Left-Top-Bottom-Right",
@@ -1908,6 +2015,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     // Highway Reinforced Bridge
     0x6Au8 => TileAttributes {
@@ -1926,6 +2034,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     0x6Bu8 => TileAttributes {
             name: "Highway Reinforced Bridge",
@@ -1943,6 +2052,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("highways"),
         },
     // Sub -> Rail
     0x6Cu8 => TileAttributes {
@@ -1961,6 +2071,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0x23,
             microsim: Microsim::None,
+            invention: Some("subways"),
         },
     0x6Du8 => TileAttributes {
             name: "Sub-Rail: Right",
@@ -1978,6 +2089,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0x23,
             microsim: Microsim::None,
+            invention: Some("subways"),
         },
     0x6Eu8 => TileAttributes {
             name: "Sub-Rail: Bottom",
@@ -1995,6 +2107,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0x23,
             microsim: Microsim::None,
+            invention: Some("subways"),
         },
     0x6Fu8 => TileAttributes {
             name: "Sub-Rail: Left",
@@ -2012,6 +2125,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0x23,
             microsim: Microsim::None,
+            invention: Some("subways"),
         },
     // Residential 1x1
     0x70u8 => TileAttributes {
@@ -2030,6 +2144,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x71u8 => TileAttributes {
             name: "Lower Class Homes 2",
@@ -2047,6 +2162,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x72u8 => TileAttributes {
             name: "Lower Class Homes 3",
@@ -2064,6 +2180,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x73u8 => TileAttributes {
             name: "Lower Class Homes 4",
@@ -2081,6 +2198,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x74u8 => TileAttributes {
             name: "Middle Class Homes 1",
@@ -2098,6 +2216,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x75u8 => TileAttributes {
             name: "Middle Class Homes 2",
@@ -2115,6 +2234,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x76u8 => TileAttributes {
             name: "Middle Class Homes 3",
@@ -2132,6 +2252,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x77u8 => TileAttributes {
             name: "Middle Class Homes 4",
@@ -2149,6 +2270,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x78u8 => TileAttributes {
             name: "Upper Class Homes 1",
@@ -2166,6 +2288,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x79u8 => TileAttributes {
             name: "Upper Class Homes 2",
@@ -2183,6 +2306,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x7Au8 => TileAttributes {
             name: "Upper Class Homes 3",
@@ -2200,6 +2324,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x7Bu8 => TileAttributes {
             name: "Upper Class Homes 4",
@@ -2217,6 +2342,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // Commercial 1x1
     0x7Cu8 => TileAttributes {
@@ -2235,6 +2361,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x7Du8 => TileAttributes {
             name: "Bed & Breakfast Inn",
@@ -2252,6 +2379,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x7Eu8 => TileAttributes {
             name: "Convenience Store",
@@ -2269,6 +2397,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x7Fu8 => TileAttributes {
             name: "Gas Station 2",
@@ -2286,6 +2415,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x80u8 => TileAttributes {
             name: "Small Office Building 1",
@@ -2303,6 +2433,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x81u8 => TileAttributes {
             name: "Small Office Building 2",
@@ -2320,6 +2451,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x82u8 => TileAttributes {
             name: "Warehouse",
@@ -2337,6 +2469,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x83u8 => TileAttributes {
             name: "Cassidy’s Toy Store",
@@ -2354,6 +2487,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // Industrial 1x1
     0x84u8 => TileAttributes {
@@ -2372,6 +2506,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x85u8 => TileAttributes {
             name: "Chemical Storage",
@@ -2389,6 +2524,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x86u8 => TileAttributes {
             name: "Small WareHouse 2",
@@ -2406,6 +2542,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x87u8 => TileAttributes {
             name: "Industral Substation",
@@ -2423,6 +2560,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // misc. 1x1
     0x88u8 => TileAttributes {
@@ -2441,6 +2579,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x89u8 => TileAttributes {
             name: "Construction 8",
@@ -2458,6 +2597,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x8Au8 => TileAttributes {
             name: "Abandoned Building 1",
@@ -2475,6 +2615,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x8Bu8 => TileAttributes {
             name: "Abandoned Building 2",
@@ -2492,6 +2633,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // Residential 2x2
     0x8Cu8 => TileAttributes {
@@ -2510,6 +2652,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x8Du8 => TileAttributes {
             name: "Small Apartments 2",
@@ -2527,6 +2670,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x8Eu8 => TileAttributes {
             name: "Small Apartments 3",
@@ -2544,6 +2688,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x8Fu8 => TileAttributes {
             name: "Medium Apartments 1",
@@ -2561,6 +2706,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x90u8 => TileAttributes {
             name: "Medium Apartments 2",
@@ -2578,6 +2724,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x91u8 => TileAttributes {
             name: "Medium Condominiums 1",
@@ -2595,6 +2742,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x92u8 => TileAttributes {
             name: "Medium Condominiums 2",
@@ -2612,6 +2760,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x93u8 => TileAttributes {
             name: "Medium Condominiums 3",
@@ -2629,6 +2778,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // Commercial 2x2
     0x94u8 => TileAttributes {
@@ -2647,6 +2797,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x95u8 => TileAttributes {
             name: "Grocery Store",
@@ -2664,6 +2815,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x96u8 => TileAttributes {
             name: "Medium Office Building 1",
@@ -2681,6 +2833,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x97u8 => TileAttributes {
             name: "Resort hotel",
@@ -2698,6 +2851,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x98u8 => TileAttributes {
             name: "Medium Office Building 2",
@@ -2715,6 +2869,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x99u8 => TileAttributes {
             name: "Office/Retail",
@@ -2732,6 +2887,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x9Au8 => TileAttributes {
             name: "Medium Office Building 3",
@@ -2749,6 +2905,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x9Bu8 => TileAttributes {
             name: "Medium Office Building 4",
@@ -2766,6 +2923,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x9Cu8 => TileAttributes {
             name: "Medium Office Building 5",
@@ -2783,6 +2941,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x9Du8 => TileAttributes {
             name: "Medium Office Building 6",
@@ -2800,6 +2959,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // 2x2 Industrial
     0x9Eu8 => TileAttributes {
@@ -2818,6 +2978,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0x9Fu8 => TileAttributes {
             name: "Chemical Processing 2",
@@ -2835,6 +2996,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xA0u8 => TileAttributes {
             name: "Small Factory 1",
@@ -2852,6 +3014,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xA1u8 => TileAttributes {
             name: "Small Factory 2",
@@ -2869,6 +3032,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xA2u8 => TileAttributes {
             name: "Small Factory 3",
@@ -2886,6 +3050,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xA3u8 => TileAttributes {
             name: "Small Factory 4",
@@ -2903,6 +3068,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xA4u8 => TileAttributes {
             name: "Small Factory 5",
@@ -2920,6 +3086,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xA5u8 => TileAttributes {
             name: "Small Factory 6",
@@ -2937,6 +3104,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // Misc 2x2
     0xA6u8 => TileAttributes {
@@ -2955,6 +3123,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xA7u8 => TileAttributes {
             name: "Construction 4",
@@ -2972,6 +3141,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xA8u8 => TileAttributes {
             name: "Construction 5",
@@ -2989,6 +3159,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xA9u8 => TileAttributes {
             name: "Construction 6",
@@ -3006,6 +3177,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xAAu8 => TileAttributes {
             name: "Abandoned Building 3",
@@ -3023,6 +3195,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xABu8 => TileAttributes {
             name: "Abandoned Building 4",
@@ -3040,6 +3213,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xACu8 => TileAttributes {
             name: "Abandoned Building 5",
@@ -3057,6 +3231,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xADu8 => TileAttributes {
             name: "Abandoned Building 6",
@@ -3074,6 +3249,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // Residential 3x3
     0xAEu8 => TileAttributes {
@@ -3092,6 +3268,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xAFu8 => TileAttributes {
             name: "Large Apartments 2",
@@ -3109,6 +3286,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xB0u8 => TileAttributes {
             name: "Large Condominiums 1",
@@ -3126,6 +3304,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xB1u8 => TileAttributes {
             name: "Large Condominiums 2",
@@ -3143,6 +3322,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // commercial 3x3
     0xB2u8 => TileAttributes {
@@ -3161,6 +3341,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xB3u8 => TileAttributes {
             name: "Office Tower 1",
@@ -3178,6 +3359,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xB4u8 => TileAttributes {
             name: "Mini Mall",
@@ -3195,6 +3377,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xB5u8 => TileAttributes {
             name: "Theater square",
@@ -3212,6 +3395,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xB6u8 => TileAttributes {
             name: "Drive In",
@@ -3229,6 +3413,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xB7u8 => TileAttributes {
             name: "Office Tower 2",
@@ -3246,6 +3431,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xB8u8 => TileAttributes {
             name: "Office Tower 3",
@@ -3263,6 +3449,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xB9u8 => TileAttributes {
             name: "Parking Lot",
@@ -3280,6 +3467,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xBAu8 => TileAttributes {
             name: "Historic Office",
@@ -3297,6 +3485,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xBBu8 => TileAttributes {
             name: "Corporate Headquarters",
@@ -3314,6 +3503,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // Industrial 3x3
     0xBCu8 => TileAttributes {
@@ -3332,6 +3522,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xBDu8 => TileAttributes {
             name: "Large Factory",
@@ -3349,6 +3540,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xBEu8 => TileAttributes {
             name: "Industrial Thingamajig",
@@ -3366,6 +3558,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xBFu8 => TileAttributes {
             name: "Medium Factory",
@@ -3383,6 +3576,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xC0u8 => TileAttributes {
             name: "Large Warehouse 1",
@@ -3400,6 +3594,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xC1u8 => TileAttributes {
             name: "Large Warehouse 2",
@@ -3417,6 +3612,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // Misc 3x3
     0xC2u8 => TileAttributes {
@@ -3435,6 +3631,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xC3u8 => TileAttributes {
             name: "Construction 2",
@@ -3452,6 +3649,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xC4u8 => TileAttributes {
             name: "Abandoned Building 7",
@@ -3469,6 +3667,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xC5u8 => TileAttributes {
             name: "Abandoned Building 8",
@@ -3486,6 +3685,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     // Power Plants
     0xC6u8 => TileAttributes {
@@ -3504,6 +3704,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Hydro,
+            invention: None,
         },
     0xC7u8 => TileAttributes {
             name: "Hydoelectric Power Plant 2",
@@ -3521,6 +3722,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Hydro,
+            invention: None,
         },
     0xC8u8 => TileAttributes {
             name: "Wind Power Plant1",
@@ -3538,6 +3740,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Wind,
+            invention: Some("wind_power"),
         },
     0xC9u8 => TileAttributes {
             name: "Gas Power Plant",
@@ -3555,6 +3758,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Gas,
+            invention: Some("gas_power"),
         },
     0xCAu8 => TileAttributes {
             name: "Oil Power Plant",
@@ -3572,6 +3776,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Oil,
+            invention: None,
         },
     0xCBu8 => TileAttributes {
             name: "Nuclear Power Plant",
@@ -3589,6 +3794,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Nuclear,
+            invention: Some("nuclear_power"),
         },
     0xCCu8 => TileAttributes {
             name: "Solar Power Plant",
@@ -3606,6 +3812,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Solar,
+            invention: Some("solar_power"),
         },
     0xCDu8 => TileAttributes {
             name: "Microwave Power Plant",
@@ -3623,6 +3830,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Microwave,
+            invention: Some("microwave_power"),
         },
     0xCEu8 => TileAttributes {
             name: "Fusion Power Plant",
@@ -3640,6 +3848,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Fusion,
+            invention: Some("fusion_power"),
         },
     0xCFu8 => TileAttributes {
             name: "Coal Power Plant",
@@ -3657,6 +3866,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Coal,
+            invention: None,
         },
     // Services/reward buildings
     0xD0u8 => TileAttributes {
@@ -3675,6 +3885,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Hall,
+            invention: None,
         },
     0xD1u8 => TileAttributes {
             name: "Hospital",
@@ -3692,6 +3903,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 50,
             underground: 0,
             microsim: Microsim::Hospital,
+            invention: None,
         },
     0xD2u8 => TileAttributes {
             name: "Police Station",
@@ -3709,6 +3921,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 100,
             underground: 0,
             microsim: Microsim::Police,
+            invention: None,
         },
     0xD3u8 => TileAttributes {
             name: "Fire Station",
@@ -3726,6 +3939,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 100,
             underground: 0,
             microsim: Microsim::Fire,
+            invention: None,
         },
     0xD4u8 => TileAttributes {
             name: "Museum",
@@ -3743,6 +3957,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Museum,
+            invention: None,
         },
     0xD5u8 => TileAttributes {
             name: "Big Park",
@@ -3760,6 +3975,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Park,
+            invention: None,
         },
     0xD6u8 => TileAttributes {
             name: "School",
@@ -3777,6 +3993,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 25,
             underground: 0,
             microsim: Microsim::School,
+            invention: None,
         },
     0xD7u8 => TileAttributes {
             name: "Stadium",
@@ -3794,6 +4011,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Stadium,
+            invention: None,
         },
     0xD8u8 => TileAttributes {
             name: "Prison",
@@ -3811,6 +4029,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Prison,
+            invention: None,
         },
     0xD9u8 => TileAttributes {
             name: "College",
@@ -3828,6 +4047,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 100,
             underground: 0,
             microsim: Microsim::Hospital,
+            invention: None,
         },
     0xDAu8 => TileAttributes {
             name: "Zoo",
@@ -3845,6 +4065,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 50, // confirm
             underground: 0,
             microsim: Microsim::Zoo,
+            invention: None,
         },
     0xDBu8 => TileAttributes {
             name: "Statue",
@@ -3862,6 +4083,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Statue,
+            invention: None,
         },
     // Infrastructure
     0xDCu8 => TileAttributes {
@@ -3880,6 +4102,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0x1C,
             microsim: Microsim::None,
+            invention: None,
         },
     0xDDu8 => TileAttributes {
             name: "Runway",
@@ -3897,6 +4120,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("airport"),
         },
     0xDEu8 => TileAttributes {
             name: "Runway Intersection",
@@ -3914,6 +4138,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("airport"),
         },
     0xDFu8 => TileAttributes {
             name: "Seaport Pier",
@@ -3931,6 +4156,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xE0u8 => TileAttributes {
             name: "Crane",
@@ -3948,6 +4174,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xE1u8 => TileAttributes {
             name: "Civilian Control Tower",
@@ -3965,6 +4192,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("airport"),
         },
     0xE2u8 => TileAttributes {
             name: "Miliary Control Tower",
@@ -3982,6 +4210,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xE3u8 => TileAttributes {
             name: "Warehouse",
@@ -3999,6 +4228,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xE4u8 => TileAttributes {
             name: "Airport Building 1",
@@ -4016,6 +4246,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("airport"),
         },
     0xE5u8 => TileAttributes {
             name: "Airport Building 1",
@@ -4033,6 +4264,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("airport"),
         },
     0xE6u8 => TileAttributes {
             name: "Tarmac",
@@ -4050,6 +4282,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("airport"),
         },
     0xE7u8 => TileAttributes {
             name: "F-15b",
@@ -4067,6 +4300,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xE8u8 => TileAttributes {
             name: "Military Hangar",
@@ -4084,6 +4318,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xE9u8 => TileAttributes {
             name: "Subway Station",
@@ -4101,6 +4336,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0x23,
             microsim: Microsim::None,
+            invention: Some("subways"),
         },
     0xEAu8 => TileAttributes {
             name: "Radar",
@@ -4118,6 +4354,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xEBu8 => TileAttributes {
             name: "Water Tower",
@@ -4135,6 +4372,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xECu8 => TileAttributes {
             name: "Bus Depot",
@@ -4152,6 +4390,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Bus,
+            invention: Some("buses"),
         },
     0xEDu8 => TileAttributes {
             name: "Rail Depot",
@@ -4169,6 +4408,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Rail,
+            invention: None,
         },
     0xEEu8 => TileAttributes {
             name: "Civilian Parking Lot",
@@ -4186,6 +4426,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xEFu8 => TileAttributes {
             name: "Military Parking Lot",
@@ -4203,6 +4444,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xF0u8 => TileAttributes {
             name: "Loading Bay",
@@ -4220,6 +4462,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xF1u8 => TileAttributes {
             name: "Top Secret",
@@ -4237,6 +4480,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xF2u8 => TileAttributes {
             name: "Cargo Yard",
@@ -4254,6 +4498,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xF3u8 => TileAttributes {
             name: "Mayor's House",
@@ -4271,6 +4516,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Mayor,
+            invention: None,
         },
     0xF4u8 => TileAttributes {
             name: "Water Treatment",
@@ -4288,6 +4534,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("water_treatment"),
         },
     0xF5u8 => TileAttributes {
             name: "Library",
@@ -4305,6 +4552,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::Library,
+            invention: None,
         },
     0xF6u8 => TileAttributes {
             name: "Big Hangar",
@@ -4322,6 +4570,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xF7u8 => TileAttributes {
             name: "Church",
@@ -4339,6 +4588,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xF8u8 => TileAttributes {
             name: "Marina",
@@ -4356,6 +4606,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
     0xF9u8 => TileAttributes {
             name: "Missile Silo",
@@ -4373,6 +4624,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0x22,
             microsim: Microsim::None,
+            invention: None,
         },
     0xFAu8 => TileAttributes {
             name: "Desalinization",
@@ -4390,6 +4642,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0x1C,
             microsim: Microsim::None,
+            invention: Some("desalinisation"),
         },
     0xFBu8 => TileAttributes {
             name: "Plymouth Arcology",
@@ -4407,6 +4660,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("plymouth"),
         },
     0xFCu8 => TileAttributes {
             name: "Forest Arcology",
@@ -4424,6 +4678,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("forest"),
         },
     0xFDu8 => TileAttributes {
             name: "Darco",
@@ -4441,6 +4696,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("darco"),
         },
     0xFEu8 => TileAttributes {
             name: "Launch Arcology",
@@ -4458,6 +4714,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: Some("launch"),
         },
     0xFFu8 => TileAttributes {
             name: "Braun Llama Dome",
@@ -4475,6 +4732,7 @@ const TILE_DATA: phf::Map<u8, TileAttributes> = phf_map! {
             maintenance: 0,
             underground: 0,
             microsim: Microsim::None,
+            invention: None,
         },
 };
 
@@ -4517,3 +4775,48 @@ pub fn get_name(building_id: &u8) -> Result<&'static str> {
         None => Err(anyhow!("invalid building id {:#04x}", building_id)),
     }
 }
+
+/// Gets the invention that has to be made before a building can be built.
+/// Args:
+///      building_id (int): id of the building.
+/// Returns:
+///      Name of the invention, or None if the building is always available.
+pub fn get_invention(building_id: &u8) -> Result<Option<&'static str>> {
+    match &TILE_DATA.get(building_id) {
+        Some(building) => Ok(building.invention),
+        None => Err(anyhow!("invalid building id {:#04x}", building_id)),
+    }
+}
+
+/// Gets the buildings the player can currently build.
+/// Args:
+///      is_invented: whether the invention with the given name has been made.
+/// Returns:
+///      Id and name of every building that costs money to build or needs an invention, sorted by id.
+pub fn buildable(is_invented: impl Fn(&str) -> bool) -> Vec<(u8, &'static str)> {
+    let mut buildable: Vec<(u8, &'static str)> = TILE_DATA
+        .entries()
+        .filter(|(_, v)| v.cost > 0 || v.invention.is_some())
+        .filter(|(_, v)| v.invention.map_or(true, &is_invented))
+        .map(|(k, v)| (*k, v.name))
+        .collect();
+
+    buildable.sort_unstable();
+    buildable
+}
+
+/// Gets the buildings that need an invention.
+/// Args:
+///      invention (str): name of the invention.
+/// Returns:
+///      Ids of the buildings, sorted.
+pub fn gated_by(invention: &str) -> Vec<u8> {
+    let mut ids: Vec<u8> = TILE_DATA
+        .entries()
+        .filter(|(_, v)| v.invention == Some(invention))
+        .map(|(k, _)| *k)
+        .collect();
+
+    ids.sort_unstable();
+    ids
+}
@@ -0,0 +1,116 @@
+// In-game calendar. The simulation counts days since January 1st of the base year, with 25 days in every month.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Days in a game month, the simulation advances one day per cycle.
+pub const DAYS_PER_MONTH: i32 = 25;
+/// Months in a game year.
+pub const MONTHS_PER_YEAR: i32 = 12;
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// A date in the game's calendar.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+pub struct Date {
+    pub year: i32,
+    /// 1 for January.
+    pub month: u8,
+    /// 1 to 25.
+    pub day: u8,
+}
+
+impl Default for Date {
+    fn default() -> Self {
+        Self::from_sim_cycle(0, 0)
+    }
+}
+
+impl Date {
+    /**
+     * Converts the simulation counter into a date.
+     * Args:
+     *      base_year (int): baseYear from MISC.
+     *      sim_cycle (int): simCycle from MISC, days since January 1st of the base year.
+     */
+    pub fn from_sim_cycle(base_year: i32, sim_cycle: i32) -> Self {
+        let months = sim_cycle.div_euclid(DAYS_PER_MONTH);
+        let day = sim_cycle.rem_euclid(DAYS_PER_MONTH);
+
+        Self::from_months(base_year, months, day)
+    }
+
+    fn from_months(base_year: i32, months: i32, day: i32) -> Self {
+        Self {
+            year: base_year.saturating_add(months.div_euclid(MONTHS_PER_YEAR)),
+            month: (months.rem_euclid(MONTHS_PER_YEAR) + 1) as u8,
+            day: (day + 1) as u8,
+        }
+    }
+
+    /**
+     * Steps back a number of whole months.
+     * Args:
+     *      months (int): number of months to go back.
+     * Returns:
+     *      The first day of the resulting month.
+     */
+    pub fn months_before(&self, months: i32) -> Self {
+        Self::from_months(self.year, i32::from(self.month) - 1 - months, 0)
+    }
+
+    /// Abbreviated name of the month, as shown in the game.
+    pub fn month_name(&self) -> &'static str {
+        MONTH_NAMES[usize::from(self.month.clamp(1, 12) - 1)]
+    }
+}
+
+/// Time the city has been running since January 1st of its base year.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CityAge {
+    pub years: i32,
+    pub months: i32,
+    pub days: i32,
+}
+
+impl CityAge {
+    /**
+     * Splits the simulation counter into years, months and days.
+     * Args:
+     *      sim_cycle (int): simCycle from MISC.
+     */
+    pub fn from_sim_cycle(sim_cycle: i32) -> Self {
+        let months = sim_cycle.div_euclid(DAYS_PER_MONTH);
+
+        Self {
+            years: months.div_euclid(MONTHS_PER_YEAR),
+            months: months.rem_euclid(MONTHS_PER_YEAR),
+            days: sim_cycle.rem_euclid(DAYS_PER_MONTH),
+        }
+    }
+}
+
+/// Age brackets of the population graphs, in years.
+pub const POPULATION_GRAPH_LABELS: [&str; 20] = [
+    "0-4", "5-9", "10-14", "15-19", "20-24", "25-29", "30-34", "35-39", "40-44", "45-49", "50-54",
+    "55-59", "60-64", "65-69", "70-74", "75-79", "80-84", "85-89", "90-94", "95-99",
+];
+
+/// Industries of the industry graphs, as in the industry window.
+pub const INDUSTRY_GRAPH_LABELS: [&str; 11] = [
+    "Steel/Mining",
+    "Textiles",
+    "Petrochemical",
+    "Food",
+    "Construction",
+    "Automotive",
+    "Aerospace",
+    "Finance",
+    "Media",
+    "Electronics",
+    "Tourism",
+];
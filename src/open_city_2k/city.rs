@@ -1,30 +1,40 @@
 use std::cmp::min;
 use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read};
 use std::path::Path;
 use std::sync::Arc;
 
-use anyhow::Result;
 use log::{debug, error, info, warn};
 use phf::phf_map;
-use serde::Serialize;
-
-use crate::open_city_2k::sc_util::int_to_bitstring;
+use schemars::{schema::RootSchema, schema_for, JsonSchema};
+use serde::{Deserialize, Serialize};
 
 use super::bit_flags::BitFlags;
+use super::bonds::BondSummary;
 use super::budget::Budget;
+use super::budget_summary::BudgetSummary;
 use super::building::Building;
-use super::buildings;
 use super::buildings::GROUNDCOVER_IDS;
 use super::buildings::HIGHWAY_2X2_IDS;
 use super::buildings::NETWORK_IDS;
+use super::calendar::{CityAge, Date, INDUSTRY_GRAPH_LABELS, POPULATION_GRAPH_LABELS};
 use super::graph::Graph;
-use super::indexed_write::IndexedWrite;
+use super::grid::Grid;
+use super::invention::{BuildableBuilding, Invention};
 use super::minimap::Minimap;
+use super::misc::Misc;
+use super::neighbour::Neighbours;
+use super::newspaper::Newspapers;
+use super::parse_error::ParseError;
 use super::sc2_iff_parse as sc2p;
-use super::sc2_iff_parse::ChunkList;
+use super::sc2_iff_parse::{ChunkList, RawChunk};
 use super::sc_util;
 use super::thing::Thing;
 use super::tile::Tile;
+use super::tile_layers::{BitFlagLayers, TileLayers};
+use super::underground::{Underground, UndergroundNetwork};
+use super::zone::{Corners, Zone};
 
 // constants
 const GRAPH_WINDOW_GRAPHS: [&str; 16] = [
@@ -51,248 +61,168 @@ const INDUSTRY_GRAPH_NAMES: [&str; 3] = [
     "industrial_tax_rate",
     "industrial_demand",
 ];
-const SIMULATOR_SETTINGS_NAMES: [&str; 9] = [
-    "YearEnd",
-    "GlobalSeaLevel",
-    "terCoast",
-    "terRiver",
-    "Military",
-    "Zoom",
-    "Compass",
-    "CityCentX",
-    "CityCentY",
-];
-const GAME_SETTING_NAME: [&str; 6] = [
-    "GameSpeed",
-    "AutoBudget",
-    "AutoGo",
-    "UserSoundOn",
-    "UserMusicOn",
-    "NoDisasters",
-];
-const INVENTION_NAMES: [&str; 17] = [
-    "gas_power",
-    "nuclear_power",
-    "solar_power",
-    "wind_power",
-    "microwave_power",
-    "fusion_power",
-    "airport",
-    "highways",
-    "buses",
-    "subways",
-    "water_treatment",
-    "desalinisation",
-    "plymouth",
-    "forest",
-    "darco",
-    "launch",
-    "highway_2",
-];
-
+/// Sections of MISC that are parsed by the city, everything else is handled by Misc.
 const MISC_PARSE_ORDER: phf::Map<u16, &'static str> = phf_map! {
-    0x0000u16 => "FirstEntry",  // nominally the same in every city.
-    0x0004u16 => "GameMode",
-    0x0008u16 => "Compass",  // rotation
-    0x000cu16 => "baseYear",
-    0x0010u16 => "simCycle",
-    0x0014u16 => "TotalFunds",
-    0x0018u16 => "TotalBonds",
-    0x001cu16 => "GameLevel",
-    0x0020u16 => "CityStatus",
-    0x0024u16 => "CityValue",
-    0x0028u16 => "LandValue",
-    0x002cu16 => "CrimeCount",
-    0x0030u16 => "TrafficCount",
-    0x0034u16 => "Pollution",
-    0x0038u16 => "CityFame",
-    0x003cu16 => "Advertising",
-    0x0040u16 => "Garbage",
-    0x0044u16 => "WorkerPercent",
-    0x0048u16 => "WorkerHealth",
-    0x004cu16 => "WorkerEducate",
-    0x0050u16 => "NationalPop",
-    0x0054u16 => "NationalValue",
-    0x0058u16 => "NationalTax",
-    0x005cu16 => "NationalTrend",
-    0x0060u16 => "heat",
-    0x0064u16 => "wind",
-    0x0068u16 => "humid",
-    0x006cu16 => "weatherTrend",
-    0x0070u16 => "NewDisaster",
-    0x0074u16 => "oldResPop",
-    0x0078u16 => "Rewards",
     0x007cu16 => "Population Graphs",
     0x016cu16 => "Industry Graphs",
     0x01f0u16 => "Tile Counts",
-    0x05f0u16 => "ZonePop|0",
-    0x05f4u16 => "ZonePop|1",
-    0x05f8u16 => "ZonePop|2",
-    0x05fcu16 => "ZonePop|3",
-    0x0600u16 => "ZonePop|4",
-    0x0604u16 => "ZonePop|5",
-    0x0608u16 => "ZonePop|6",
-    0x060cu16 => "ZonePop|7",
-    0x0610u16 => "Bonds",
     0x06d8u16 => "Neighbours",
-    0x0718u16 => "Valve?|0",  // reverse engineered from the game, may be a typo in original.
-    0x071cu16 => "Valve?|1",
-    0x0720u16 => "Valve?|2",
-    0x0724u16 => "Valve?|3",
-    0x0728u16 => "Valve?|4",
-    0x072cu16 => "Valve?|5",
-    0x0730u16 => "Valve?|6",
-    0x0734u16 => "Valve?|7",
-    0x0738u16 => "gas_power",
-    0x073cu16 => "nuclear_power",
-    0x0740u16 => "solar_power",
-    0x0744u16 => "wind_power",
-    0x0748u16 => "microwave_power",
-    0x074cu16 => "fusion_power",
-    0x0750u16 => "airport",
-    0x0754u16 => "highways",
-    0x0758u16 => "buses",
-    0x075cu16 => "subways",
-    0x0760u16 => "water_treatment",
-    0x0764u16 => "desalinisation",
-    0x0768u16 => "plymouth",
-    0x076cu16 => "forest",
-    0x0770u16 => "darco",
-    0x0774u16 => "launch",
-    0x0778u16 => "highway_2",
     0x077cu16 => "Budget",
-    0x0e3cu16 => "YearEnd",
-    0x0e40u16 => "GlobalSeaLevel",
-    0x0e44u16 => "terCoast",
-    0x0e48u16 => "terRiver",
-    0x0e4cu16 => "Military",
-    0x0e50u16 => "Paper List",
-    0x0ec8u16 => "News List",
-    0x0fa0u16 => "Ordinances",
-    0x0fa4u16 => "unemployed",
-    0x0fa8u16 => "Military Count",
-    0x0fe8u16 => "SubwayCnt",
-    0x0fecu16 => "GameSpeed",
-    0x0ff0u16 => "AutoBudget",
-    0x0ff4u16 => "AutoGo",
-    0x0ff8u16 => "UserSoundOn",
-    0x0ffcu16 => "UserMusicOn",
-    0x1000u16 => "NoDisasters",
-    0x1004u16 => "PaperDeliver",
-    0x1008u16 => "PaperExtra",
-    0x100cu16 => "PaperChoice",
-    0x1010u16 => "unknown128",
-    0x1014u16 => "Zoom",
-    0x1018u16 => "CityCentX",
-    0x101cu16 => "CityCentY",
-    0x1020u16 => "GlobalArcoPop",
-    0x1024u16 => "ConnectTiles",
-    0x1028u16 => "TeamsActive",
-    0x102cu16 => "TotalPop",
-    0x1030u16 => "IndustryBonus",
-    0x1034u16 => "PolluteBonus",
-    0x1038u16 => "oldArrest",
-    0x103cu16 => "PoliceBonus",
-    0x1040u16 => "DisasterObject",
-    0x1044u16 => "CurrentDisaster",
-    0x1048u16 => "GoDisaster",
-    0x104cu16 => "SewerBonus",
-    0x1050u16 => "Extra",
 };
 
-#[derive(Default, Debug, Serialize)]
-struct Neighbour {
-    name: Vec<i32>,
-    population: Vec<i32>,
-    value: Vec<i32>,
-    fame: Vec<i32>,
-}
-
-impl IndexedWrite<i32> for Neighbour {
-    fn field_len() -> usize {
-        4
-    }
-
-    fn write(&mut self, index: usize, value: i32) {
-        match index {
-            0 => self.name.push(value),
-
-            1 => self.population.push(value),
-
-            2 => self.value.push(value),
-
-            3 => self.fame.push(value),
-
-            _ => panic!("index out of bounds"),
-        }
-    }
-}
+/// Version of the serialized city layout. Bumped whenever fields are renamed, removed or change their meaning.
+pub const FORMAT_VERSION: u32 = 13;
 
 /// Class to store all of a city information, including buildings and all other tile contents, MISC city data, minimaps, etc.
 /// Also handles serializing a city back out to a complaint .sc2 (or .scn file).
-#[derive(Debug, Serialize)]
+///
+/// The tilelist and minimaps are stored as dense row-major grids. Maps keyed by tile coordinates use (x, y) tuple keys
+/// in binary formats like MessagePack and "x,y" string keys in JSON.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct City {
+    /// Version of this layout, see FORMAT_VERSION. Missing in files written before it was introduced, those can't be
+    /// read back since their layout is incompatible.
+    #[serde(default)]
+    format_version: u32,
+    /// Name of the city from CNAM.
     city_name: String,
+    /// Sign texts from XLAB, one per slot so XTXT text pointers index into it. Empty slots are empty strings, slot 0
+    /// is the mayor's name.
     labels: Vec<String>,
+    /// Raw 8 byte records of the micro simulators from XMIC.
     microsim_state: Vec<Box<[u8]>>,
-    tilelist: HashMap<(usize, usize), Tile>,
+    /// Every tile of the map as a city_size x city_size grid.
+    tilelist: Grid<Tile>,
+    /// Buildings keyed by their left corner.
+    #[serde(with = "super::cord_hash_map")]
+    #[schemars(with = "HashMap<String, Arc<Building>>")]
     buildings: HashMap<(usize, usize), Arc<Building>>,
+    /// Roads, rails, power lines and other above ground networks.
+    #[serde(with = "super::cord_hash_map")]
+    #[schemars(with = "HashMap<String, Arc<Building>>")]
     networks: HashMap<(usize, usize), Arc<Building>>,
+    /// Trees, rubble and radioactivity.
+    #[serde(with = "super::cord_hash_map")]
+    #[schemars(with = "HashMap<String, Arc<Building>>")]
     groundcover: HashMap<(usize, usize), Arc<Building>>,
+    /// Water pipes, derived from XUND.
+    #[serde(default)]
+    pipe_network: UndergroundNetwork,
+    /// Subway lines and stations, derived from XUND.
+    #[serde(default)]
+    subway_network: UndergroundNetwork,
+    /// Moving objects like vehicles and disasters from XTHG.
     things: Vec<Thing>,
+    /// Width and height of the map in tiles.
     city_size: usize,
+    /// Graphs from XGRP, keyed by their name in the graph window.
     graphs: HashMap<String, Graph>,
-    city_attributes: HashMap<String, i32>,
+    /// Settings, statistics and lists from MISC.
+    misc: Misc,
+    /// Current in-game date, derived from baseYear and simCycle.
+    #[serde(default)]
+    date: Date,
+    /// Time since January 1st of the base year.
+    #[serde(default)]
+    city_age: CityAge,
+    /// Inventions and the buildings they unlock, derived from the invention years and the date.
+    #[serde(default)]
+    inventions: Vec<Invention>,
+    /// Buildings that can be built with the current inventions.
+    #[serde(default)]
+    buildable_now: Vec<BuildableBuilding>,
+    /// Newspaper settings and recent newspapers, derived from the Paper List and News List.
+    #[serde(default)]
+    newspapers: Newspapers,
+    /// Budget from MISC, missing if the city couldn't be parsed far enough.
     budget: Option<Budget>,
-    neighbor_info: Vec<Neighbour>,
+    /// Outstanding bonds, derived from the budget and TotalBonds.
+    #[serde(default)]
+    bond_summary: BondSummary,
+    /// Monthly series, yearly totals and projected funds, derived from the budget and TotalFunds.
+    #[serde(default)]
+    budget_summary: BudgetSummary,
+    /// The four neighbouring cities.
+    neighbours: Neighbours,
+    /// Number of tiles per building id.
     building_count: Vec<i32>,
-    game_settings: HashMap<String, i32>,
-    inventions: HashMap<String, i32>,
+    /// Population graphs from MISC, keyed by name.
     population_graphs: HashMap<String, Vec<i32>>,
+    /// Age bracket of every entry in the population graphs.
+    #[serde(default)]
+    population_graph_labels: Vec<String>,
+    /// Industry graphs from MISC, keyed by name.
     industry_graphs: HashMap<String, Vec<i32>>,
-    simulator_settings: HashMap<String, i32>,
+    /// Industry of every entry in the industry graphs.
+    #[serde(default)]
+    industry_graph_labels: Vec<String>,
+    /// Traffic minimap from XTRF.
     pub(crate) traffic: Minimap,
+    /// Pollution minimap from XPLT.
     pub(crate) pollution: Minimap,
+    /// Land value minimap from XVAL.
     pub(crate) value: Minimap,
+    /// Crime minimap from XCRM.
     pub(crate) crime: Minimap,
+    /// Police coverage minimap from XPLC.
     pub(crate) police: Minimap,
+    /// Fire coverage minimap from XFIR.
     pub(crate) fire: Minimap,
+    /// Population density minimap from XPOP.
     pub(crate) density: Minimap,
+    /// Rate of growth minimap from XROG.
     pub(crate) growth: Minimap,
+    /// Whether the file is a scenario.
     is_scenario: bool,
+    /// Scenario title from TEXT.
     scenario_text: String,
+    /// Scenario description from TEXT.
     scenario_descriptive_text: String,
+    /// Win conditions from SCEN.
     scenario_condition: HashMap<String, u32>,
+    /// Scenario picture from PICT.
     scenario_pict: Vec<u8>,
+    /// TEXT, SCEN, PICT and unknown chunks as they were read, written back unchanged.
+    #[serde(default)]
+    raw_chunks: Vec<RawChunk>,
+    /// Name of the file the city was read from.
     original_filename: String,
 }
 
 impl City {
     fn new() -> Self {
         Self {
+            format_version: FORMAT_VERSION,
             city_name: String::from(""),
             labels: vec![],
             microsim_state: vec![],
             //graph_data: HashMap::new(),
-            tilelist: HashMap::new(),
+            tilelist: Grid::default(),
             buildings: HashMap::new(), // Note that this stores *only* buildings.
             networks: HashMap::new(), // Stores roads, rails, powerlines and other things that are above ground networks.
             groundcover: HashMap::new(), // Stores trees, rubble and radioactivity.
+            pipe_network: UndergroundNetwork::default(),
+            subway_network: UndergroundNetwork::default(),
             things: vec![],
             city_size: 128,
             graphs: HashMap::new(),
 
             // Stuff from Misc
-            city_attributes: HashMap::new(),
+            misc: Misc::default(),
+            date: Date::default(),
+            city_age: CityAge::default(),
+            inventions: vec![],
+            buildable_now: vec![],
+            newspapers: Newspapers::default(),
             budget: None, // original was null
-            neighbor_info: vec![],
-            building_count: Vec::with_capacity(256),
-            simulator_settings: HashMap::new(),
-            inventions: HashMap::new(),
+            bond_summary: BondSummary::default(),
+            budget_summary: BudgetSummary::default(),
+            neighbours: Neighbours::default(),
+            building_count: vec![0; 256],
             population_graphs: HashMap::new(),
+            population_graph_labels: vec![],
             industry_graphs: HashMap::new(),
-
-            game_settings: HashMap::new(),
+            industry_graph_labels: vec![],
 
             // Minimaps
             traffic: Minimap::new(String::from("traffic"), 64),
@@ -310,11 +240,245 @@ impl City {
             scenario_descriptive_text: String::from(""),
             scenario_condition: HashMap::new(),
             scenario_pict: vec![],
+            raw_chunks: vec![],
 
             original_filename: String::from(""),
         }
     }
 
+    /**
+     * Generates the JSON Schema of a serialized city.
+     * Returns:
+     *      Schema describing the JSON output, see FORMAT_VERSION for its version.
+     */
+    pub fn json_schema() -> RootSchema {
+        schema_for!(City)
+    }
+
+    pub fn format_version(&self) -> u32 {
+        self.format_version
+    }
+
+    pub fn city_name(&self) -> &str {
+        &self.city_name
+    }
+
+    pub fn original_filename(&self) -> &str {
+        &self.original_filename
+    }
+
+    pub fn city_size(&self) -> usize {
+        self.city_size
+    }
+
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    pub fn microsim_state(&self) -> &[Box<[u8]>] {
+        &self.microsim_state
+    }
+
+    pub fn tile(&self, tile_coords: (usize, usize)) -> Option<&Tile> {
+        self.tilelist.get(tile_coords)
+    }
+
+    pub fn tilelist(&self) -> &Grid<Tile> {
+        &self.tilelist
+    }
+
+    /**
+     * Splits the tilelist into one plane per tile attribute.
+     * Returns:
+     *      city_size x city_size layers of the tiles.
+     */
+    pub fn tile_layers(&self) -> TileLayers {
+        let flag = |read: fn(&BitFlags) -> bool| {
+            self.tilelist
+                .map(|tile| tile.bit_flags().as_ref().map(read).unwrap_or_default())
+        };
+
+        TileLayers {
+            format_version: FORMAT_VERSION,
+            city_size: self.city_size,
+            altitude: self.tilelist.map(|tile| tile.altitude() as u8),
+            water: self.tilelist.map(Tile::is_water),
+            terrain_raw: self.tilelist.map(|tile| *tile.terrain()),
+            zone: self.tilelist.map(|tile| tile.zone().to_u8()),
+            zone_corners: self.tilelist.map(|tile| tile.zone_corners().bits()),
+            underground: self.tilelist.map(|tile| *tile.underground()),
+            building_id: self.tilelist.map(|tile| {
+                self.building_at(tile.coordinates())
+                    .map(|building| building.building_id)
+                    .unwrap_or_default()
+            }),
+            text_pointer: self.tilelist.map(|tile| tile.text_pointer() as u8),
+            bit_flags: BitFlagLayers {
+                powerable: flag(BitFlags::powerable),
+                powered: flag(BitFlags::powered),
+                piped: flag(BitFlags::piped),
+                watered: flag(BitFlags::watered),
+                xval: flag(BitFlags::xval),
+                water: flag(BitFlags::water),
+                rotate: flag(BitFlags::rotate),
+                salt: flag(BitFlags::salt),
+            },
+        }
+    }
+
+    pub fn buildings(&self) -> &HashMap<(usize, usize), Arc<Building>> {
+        &self.buildings
+    }
+
+    pub fn networks(&self) -> &HashMap<(usize, usize), Arc<Building>> {
+        &self.networks
+    }
+
+    pub fn groundcover(&self) -> &HashMap<(usize, usize), Arc<Building>> {
+        &self.groundcover
+    }
+
+    pub fn pipe_network(&self) -> &UndergroundNetwork {
+        &self.pipe_network
+    }
+
+    pub fn subway_network(&self) -> &UndergroundNetwork {
+        &self.subway_network
+    }
+
+    pub fn things(&self) -> &[Thing] {
+        &self.things
+    }
+
+    pub fn graphs(&self) -> &HashMap<String, Graph> {
+        &self.graphs
+    }
+
+    pub fn misc(&self) -> &Misc {
+        &self.misc
+    }
+
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    pub fn city_age(&self) -> CityAge {
+        self.city_age
+    }
+
+    pub fn inventions(&self) -> &[Invention] {
+        &self.inventions
+    }
+
+    pub fn buildable_now(&self) -> &[BuildableBuilding] {
+        &self.buildable_now
+    }
+
+    pub fn newspapers(&self) -> &Newspapers {
+        &self.newspapers
+    }
+
+    pub fn budget(&self) -> Option<&Budget> {
+        self.budget.as_ref()
+    }
+
+    pub fn bond_summary(&self) -> &BondSummary {
+        &self.bond_summary
+    }
+
+    pub fn budget_summary(&self) -> &BudgetSummary {
+        &self.budget_summary
+    }
+
+    pub fn neighbours(&self) -> &Neighbours {
+        &self.neighbours
+    }
+
+    /**
+     * Fills in the names of the neighbouring cities.
+     * Args:
+     *      names: the game's neighbour city names, indexed by the neighbours' name_index.
+     */
+    pub fn resolve_neighbour_names(&mut self, names: &[impl AsRef<str>]) {
+        self.neighbours.resolve_names(names);
+    }
+
+    pub fn building_count(&self) -> &[i32] {
+        &self.building_count
+    }
+
+    pub fn population_graphs(&self) -> &HashMap<String, Vec<i32>> {
+        &self.population_graphs
+    }
+
+    pub fn population_graph_labels(&self) -> &[String] {
+        &self.population_graph_labels
+    }
+
+    pub fn industry_graphs(&self) -> &HashMap<String, Vec<i32>> {
+        &self.industry_graphs
+    }
+
+    pub fn industry_graph_labels(&self) -> &[String] {
+        &self.industry_graph_labels
+    }
+
+    pub fn traffic(&self) -> &Minimap {
+        &self.traffic
+    }
+
+    pub fn pollution(&self) -> &Minimap {
+        &self.pollution
+    }
+
+    pub fn value(&self) -> &Minimap {
+        &self.value
+    }
+
+    pub fn crime(&self) -> &Minimap {
+        &self.crime
+    }
+
+    pub fn police(&self) -> &Minimap {
+        &self.police
+    }
+
+    pub fn fire(&self) -> &Minimap {
+        &self.fire
+    }
+
+    pub fn density(&self) -> &Minimap {
+        &self.density
+    }
+
+    pub fn growth(&self) -> &Minimap {
+        &self.growth
+    }
+
+    pub fn is_scenario(&self) -> bool {
+        self.is_scenario
+    }
+
+    pub fn scenario_text(&self) -> &str {
+        &self.scenario_text
+    }
+
+    pub fn scenario_descriptive_text(&self) -> &str {
+        &self.scenario_descriptive_text
+    }
+
+    pub fn scenario_condition(&self) -> &HashMap<String, u32> {
+        &self.scenario_condition
+    }
+
+    pub fn scenario_pict(&self) -> &[u8] {
+        &self.scenario_pict
+    }
+
+    pub fn raw_chunks(&self) -> &[RawChunk] {
+        &self.raw_chunks
+    }
+
     /**
      * Creates the 8 minimaps.
      * Args:
@@ -410,9 +574,15 @@ impl City {
     fn create_tilelist(&mut self, raw_sc2_data: &ChunkList) {
         info!("parsing city terrain tiles...");
 
+        let tile_count = self.city_size * self.city_size;
+        let placeholders = (0..tile_count).map(|_| Tile::new()).collect();
+
+        self.tilelist = Grid::from_cells(self.city_size, self.city_size, placeholders)
+            .expect("should have one tile per cell");
+
         for row in 0..self.city_size {
             for col in 0..self.city_size {
-                let mut tile = Tile::new(self.labels.clone());
+                let mut tile = Tile::new();
                 let tile_idx = row * self.city_size + col;
                 let tile_coords = (row, col);
 
@@ -427,37 +597,40 @@ impl City {
                 let xter = &raw_sc2_data.xter()[tile_idx..(tile_idx + 1)]
                     .try_into()
                     .expect("should be 1 byte");
-                //			let altm_bits = sc_util::parse_uint16(altm);
-                let altm_bits = sc_util::int_to_bitstring(sc_util::parse_uint16(altm) as u32, 16);
-                //			tile.altitude_tunnel = altm_bits & 0xFF
-                tile.set_altitude_tunnel(sc_util::parse_bitstring(&altm_bits[0..8]));
-                //			tile.is_water = bool(altm_bits & 0x100)
-                tile.set_is_water(sc_util::parse_bitstring(&altm_bits[8..9]) > 0);
-                //			tile.altitude_unknown = altm_bits & 0x600
-                tile.set_altitude_unknown(sc_util::parse_bitstring(&altm_bits[9..11]));
-                //			tile.altitude = altm_bits & 0xF800
-                tile.set_altitude(sc_util::parse_bitstring(&altm_bits[11..]));
+                let altm_bits = sc_util::parse_uint16(altm) as u32;
+                // Bits 15 - 8 are the tunnel depth, 7 is water, 6 - 5 are unknown and 4 - 0 the altitude.
+                tile.set_altitude_tunnel(altm_bits >> 8);
+                tile.set_is_water(altm_bits & 0x80 != 0);
+                tile.set_altitude_unknown((altm_bits >> 5) & 0b11);
+                tile.set_altitude(altm_bits & 0x1F);
                 tile.set_terrain(sc_util::parse_uint8(xter));
+                tile.set_building_id(raw_sc2_data.xbld()[tile_idx]);
 
-                debug!("altm: {}, xter: {}", altm_bits, tile.terrain());
+                debug!(
+                    "altm: {:016b}, xter: {}, terrain: {:?}",
+                    altm_bits,
+                    tile.terrain(),
+                    tile.decoded_terrain()
+                );
 
                 // Next parse city stuff.
                 // skip self.building for now, it's handled specially.
-                let xzon = &raw_sc2_data.xzon()[tile_idx..(tile_idx + 1)]
-                    .try_into()
-                    .expect("should be 1 byte");
-                let xzon_bits = sc_util::int_to_bitstring(sc_util::parse_uint8(xzon) as u32, 8);
-                tile.set_zone_corners(xzon_bits[0..4].to_string());
-                tile.set_zone(sc_util::parse_bitstring(&xzon_bits[4..]));
+                let xzon = raw_sc2_data.xzon()[tile_idx];
+                let zone = Zone::from_xzon(xzon);
+                if let Zone::Unknown(value) = zone {
+                    warn!("Unknown zone {value} at XZON offset {tile_idx}, keeping the raw value.");
+                }
+                tile.set_zone_corners(Corners::from_xzon(xzon));
+                tile.set_zone(zone);
                 let xund = &raw_sc2_data.xund()[tile_idx..(tile_idx + 1)]
                     .try_into()
                     .expect("should be 1 byte");
                 tile.set_underground(sc_util::parse_uint8(xund));
 
                 debug!(
-                    "zone: {}, corners: {}, underground: {}",
+                    "zone: {:?}, corners: {:04b}, underground: {}",
                     tile.zone(),
-                    tile.zone_corners(),
+                    tile.zone_corners().bits(),
                     tile.underground()
                 );
 
@@ -480,7 +653,7 @@ impl City {
                 );
 
                 // Add the new tile to the tilelist
-                self.tilelist.insert((row, col), tile);
+                self.tilelist[(row, col)] = tile;
 
                 debug!(
                     "Tile: {}",
@@ -491,6 +664,69 @@ impl City {
         }
     }
 
+    /**
+     * Collects the outstanding bonds from the budget.
+     */
+    fn summarize_bonds(&mut self) {
+        let total_bonds = self.misc.total_bonds;
+
+        if let Some(budget) = &self.budget {
+            self.bond_summary = BondSummary::new(budget.bonds(), total_bonds);
+
+            if !self.bond_summary.total_bonds_matches {
+                warn!(
+                    "TotalBonds is {}, but the outstanding bonds add up to {}",
+                    total_bonds, self.bond_summary.outstanding_debt
+                );
+            }
+        }
+
+        debug!(
+            "{} bonds, debt: {}, TotalBonds: {}",
+            self.bond_summary.bonds.len(),
+            self.bond_summary.outstanding_debt,
+            total_bonds
+        );
+    }
+
+    /**
+     * Sums up the monthly budget data and projects the funds to the end of the year.
+     */
+    fn summarize_budget(&mut self) {
+        let funds = self.misc.total_funds;
+        let current_month = self.date().month;
+
+        if let Some(budget) = &self.budget {
+            self.budget_summary = budget.summary(funds, current_month);
+        }
+
+        debug!(
+            "yearly income: {}, expense: {}, projected funds: {}",
+            self.budget_summary.yearly_income,
+            self.budget_summary.yearly_expense,
+            self.budget_summary.projected_year_end_funds
+        );
+    }
+
+    /**
+     * Builds the pipe and subway networks from the underground of the tiles.
+     */
+    fn create_underground_networks(&mut self) {
+        info!("parsing underground networks...");
+
+        let underground = self.tilelist.map(Tile::decoded_underground);
+
+        self.pipe_network = UndergroundNetwork::from_grid(&underground, Underground::pipe_openings);
+        self.subway_network =
+            UndergroundNetwork::from_grid(&underground, Underground::subway_openings);
+
+        debug!(
+            "pipes: {} tiles, subways: {} tiles",
+            self.pipe_network.tiles().len(),
+            self.subway_network.tiles().len()
+        );
+    }
+
     /**
      * Parses the label data.
      * Todo: Make handling of "special" labels easier.
@@ -500,21 +736,21 @@ impl City {
     fn parse_labels(&mut self, xlab_segment: &[u8]) {
         info!("parsing labels...");
 
-        for x in (0..xlab_segment.len()).step_by(25) {
-            let label_id = x / 25;
-            let raw_label = &xlab_segment[x..(x + 25)];
+        for (label_id, raw_label) in xlab_segment.chunks_exact(25).enumerate() {
             let label_len =
                 sc_util::parse_uint8(raw_label[0..1].try_into().expect("should be 1 byte"));
 
-            if label_len == 0 {
-                continue;
-            }
-
-            let label = String::from_utf8_lossy(&raw_label[1..(1 + label_len as usize)]);
+            // Labels can hold at most 24 characters, anything longer is garbage.
+            let label_end = min(1 + label_len as usize, raw_label.len());
+            // Decoded as Latin-1 so every byte maps to a single char and can be written back unchanged.
+            let label: String = raw_label[1..label_end].iter().map(|b| *b as char).collect();
 
-            self.labels.push(label.clone().into_owned());
+            if label_len != 0 {
+                debug!("Label: {label_id}: '{label}'");
+            }
 
-            debug!("Label: {label_id}: '{label}'");
+            // Empty slots are kept, so the labels stay at the index the text pointers refer to.
+            self.labels.push(label);
         }
     }
 
@@ -527,10 +763,7 @@ impl City {
     fn parse_microsim(&mut self, xmic_segment: &[u8]) {
         info!("parsing micro simulation data...");
 
-        for x in (0..xmic_segment.len()).step_by(8) {
-            let microsim_id = x / 8;
-            let microsim = &xmic_segment[x..(x + 8)];
-
+        for (microsim_id, microsim) in xmic_segment.chunks_exact(8).enumerate() {
             self.microsim_state.push(Box::from(microsim));
 
             debug!("Raw Microsim: {microsim_id}: {microsim:?}");
@@ -546,12 +779,8 @@ impl City {
     fn parse_things(&mut self, xthg_segments: &[u8]) {
         info!("parsing things...");
 
-        for idx in (0..xthg_segments.len()).step_by(12) {
-            let thing_data = xthg_segments[idx..(idx + 12)]
-                .try_into()
-                .expect("should be 12 bytes");
-            let thing_index = idx / 12;
-            let thing = Thing::parse_thing(thing_data);
+        for (thing_index, thing_data) in xthg_segments.chunks_exact(12).enumerate() {
+            let thing = Thing::parse_thing(thing_data.try_into().expect("should be 12 bytes"));
 
             debug!("Index: {thing_index}, {}", thing.to_string());
 
@@ -571,12 +800,14 @@ impl City {
 
         for (idx, graph_name) in GRAPH_WINDOW_GRAPHS.iter().enumerate() {
             let graph_start = idx * segment_len;
-            let graph = Graph::parse_graph(
+            let mut graph = Graph::parse_graph(
                 xgrp_segment[graph_start..(graph_start + segment_len)]
                     .try_into()
                     .expect("should be 212 bytes"),
             );
 
+            graph.set_dates(self.date);
+
             debug!("Graph: {graph_name}\n{}", graph.to_string());
 
             self.graphs.insert(graph_name.to_string(), graph);
@@ -587,8 +818,10 @@ impl City {
      * Parses the scenario information.
      * Args:
      *     raw_city_data (bytes): Raw data to parse scenario information out of.
+     * Raises:
+     *     ParseError: TEXT, SCEN or PICT are shorter than their contents claim.
      */
-    fn parse_scenario(&mut self, raw_city_data: &ChunkList) {
+    fn parse_scenario(&mut self, raw_city_data: &ChunkList) -> Result<(), ParseError> {
         info!("parsing city scenario...");
 
         self.is_scenario = true;
@@ -598,7 +831,8 @@ impl City {
         let picture = raw_city_data.pict();
 
         for entry in raw_text {
-            let string_id = u32::from_be_bytes(entry[0..4].try_into().expect("should be 4 bytes"));
+            let raw_id = ParseError::check_range("TEXT", entry, 0, 4)?;
+            let string_id = u32::from_be_bytes(raw_id.try_into().expect("should be 4 bytes"));
             let raw_string = String::from_utf8_lossy(&entry[4..entry.len()]).replace('\r', "\n");
 
             if string_id == 0x80000000 {
@@ -641,7 +875,7 @@ impl City {
         for (k, v) in contents {
             conditions.insert(
                 k.to_string(),
-                sc_util::bytes_to_uint(&raw_scenario[offset..(offset + v)]),
+                sc_util::bytes_to_uint(ParseError::check_range("SCEN", raw_scenario, offset, v)?),
             );
 
             offset += v;
@@ -651,7 +885,8 @@ impl City {
 
         self.scenario_condition = conditions;
 
-        let header = &picture[0..4];
+        let picture_header = ParseError::check_range("PICT", picture, 0, 8)?;
+        let header = &picture_header[0..4];
 
         if header != [0x80, 0x00, 0x00, 0x00] {
             error!("Scenario PICT parsing failed. {header:?}"); //# todo: exception?
@@ -661,12 +896,12 @@ impl City {
         let mut row_length: usize = 0;
         let mut row_count: usize = 0;
 
-        for (idx, byte) in picture[4..6].iter().enumerate() {
+        for (idx, byte) in picture_header[4..6].iter().enumerate() {
             // x dimension of image.
             row_length |= (*byte as usize) << idx;
         }
 
-        for (idx, byte) in picture[6..8].iter().enumerate() {
+        for (idx, byte) in picture_header[6..8].iter().enumerate() {
             // y dimension of image.
             row_count |= (*byte as usize) << idx;
         }
@@ -678,11 +913,8 @@ impl City {
 
         for row_idx in 0..row_count {
             let row_start = row_idx * (row_length + 1);
-            let mut row = vec![];
-
-            for x in &picture_data[row_start..(row_start + row_length + 1)] {
-                row.push(x.to_owned());
-            }
+            let mut row =
+                ParseError::check_range("PICT", picture_data, row_start, row_length + 1)?.to_vec();
 
             if *row.last().expect("should have at least one") != 255 {
                 row = Vec::with_capacity(row_length);
@@ -698,6 +930,31 @@ impl City {
         }
 
         self.scenario_pict = image_data;
+
+        Ok(())
+    }
+
+    /**
+     * Creates a building for a tile of XBLD.
+     * Args:
+     *      building_id (int): id of the building.
+     *      tile_coords: coordinates of the tile.
+     *      tile_idx (int): offset of the tile in XBLD.
+     * Raises:
+     *      ParseError: the building id is not in the building table.
+     */
+    fn create_building(
+        building_id: u8,
+        tile_coords: (usize, usize),
+        tile_idx: usize,
+    ) -> Result<Arc<Building>, ParseError> {
+        let building =
+            Building::new(building_id, tile_coords).ok_or(ParseError::UnknownBuildingId {
+                building_id,
+                offset: tile_idx,
+            })?;
+
+        Ok(Arc::new(building))
     }
 
     /**
@@ -710,32 +967,46 @@ impl City {
      *      Buildings are stored as a dictionary, where a tile's xy coordinates are the key. Each tile of a building will point back to the same builiding object. This handles holes in the building.
      * Args:
      *      raw_sc2_data: Raw data for the city.
+     * Raises:
+     *      ParseError: the city has an invalid rotation or contains an unknown building.
      */
-    fn find_buildings(&mut self, raw_sc2_data: &ChunkList) -> Result<()> {
+    fn find_buildings(&mut self, raw_sc2_data: &ChunkList) -> Result<(), ParseError> {
         info!("parsing city buildings...");
 
         // If the city has been rotated, then what is considered the left corrner changes.
-        let city_rotation = self.simulator_settings["Compass"];
-        let corner = [0b1000, 0b0001, 0b0010, 0b0100];
-        let left_corner = corner[city_rotation as usize];
-
-        debug!("City has rotation {}.", city_rotation);
+        let city_rotation = self.misc.compass;
+        let corner = [Corners::LEFT, Corners::BOTTOM, Corners::RIGHT, Corners::TOP];
+        let left_corner = *city_rotation
+            .rotation()
+            .and_then(|rotation| corner.get(rotation))
+            .ok_or_else(|| ParseError::InvalidValue {
+                chunk_id: String::from("MISC"),
+                offset: 0x0008,
+                value: city_rotation.to_i32().into(),
+            })?;
+
+        debug!("City has rotation {:?}.", city_rotation);
 
         let raw_xbld = raw_sc2_data.xbld();
 
         for row in 0..self.city_size {
             for col in 0..self.city_size {
                 // Find left corner.
-                let zone_mask = self.tilelist[&(row, col)].zone_corners();
+                let zone_corners = self.tilelist[(row, col)].zone_corners();
                 let tile_idx = row * self.city_size + col;
                 let building_id = raw_xbld[tile_idx];
 
                 debug!("Checking building at: ({row}, {col})");
-                debug!("Zone Mask: {}, {:04b}", zone_mask, left_corner);
+                debug!(
+                    "Zone corners: {:04b}, {:04b}",
+                    zone_corners.bits(),
+                    left_corner.bits()
+                );
 
-                match zone_mask {
-                    mask if sc_util::parse_bitstring(mask) & left_corner != 0 => {
-                        let new_building = Arc::new(Building::new(building_id, (row, col)));
+                match zone_corners {
+                    corners if corners.contains(left_corner) => {
+                        let new_building =
+                            Self::create_building(building_id, (row, col), tile_idx)?;
 
                         // Certain highway pieces are 2x2 buildings, but should only be in networks.
                         match building_id {
@@ -748,12 +1019,12 @@ impl City {
                             }
                         }
 
-                        match self.tilelist.get_mut(&(row, col)) {
+                        match self.tilelist.get_mut((row, col)) {
                             Some(tile) => tile.set_building(new_building.clone()),
                             None => warn!("WARNING: no tile at ({row}, {col})"),
                         }
 
-                        let building_size = buildings::get_size(&building_id)?;
+                        let building_size = new_building.size();
 
                         debug!("Found Building: {building_id} with size: {building_size} at ({row}, {col})");
 
@@ -762,11 +1033,11 @@ impl City {
                             continue;
                         }
 
-                        // The clamping to 127 is to deal with certain industrial 3x3 buildings that glitch out on the edge of the map.
-                        for building_x in row..min(row + building_size, 127) {
+                        // The clamping is to deal with certain industrial 3x3 buildings that glitch out on the edge of the map.
+                        for building_x in row..min(row + building_size, self.city_size) {
                             debug!("col: {}, building: {}", col, building_size);
 
-                            for building_y in ((col - (building_size - 1))..=col).rev() {
+                            for building_y in (col.saturating_sub(building_size - 1)..=col).rev() {
                                 let next_tile_idx = building_x * self.city_size + building_y;
                                 let new_building_id = raw_xbld[next_tile_idx];
 
@@ -779,7 +1050,7 @@ impl City {
                                                 new_building.clone(),
                                             );
                                         } else {
-                                            match self.tilelist.get_mut(&(building_x, building_y)) {
+                                            match self.tilelist.get_mut((building_x, building_y)) {
                                                 Some(tile) => {
                                                     tile.set_building(new_building.clone())
                                                 }
@@ -805,11 +1076,12 @@ impl City {
                     // Why are groundcover and networks treated differently?
                     // Because it seems (seemed?) to add flexibility.
                     _ if GROUNDCOVER_IDS.contains(&building_id) => {
-                        let new_building = Arc::new(Building::new(building_id, (row, col)));
+                        let new_building =
+                            Self::create_building(building_id, (row, col), tile_idx)?;
 
                         self.groundcover.insert((row, col), new_building.clone());
 
-                        match self.tilelist.get_mut(&(row, col)) {
+                        match self.tilelist.get_mut((row, col)) {
                             Some(tile) => tile.set_building(new_building),
                             None => warn!("WARNING: no tile at ({row}, {col})"),
                         }
@@ -821,11 +1093,12 @@ impl City {
                     _ if NETWORK_IDS.contains(&building_id)
                         && !HIGHWAY_2X2_IDS.contains(&building_id) =>
                     {
-                        let new_building = Arc::new(Building::new(building_id, (row, col)));
+                        let new_building =
+                            Self::create_building(building_id, (row, col), tile_idx)?;
 
                         self.networks.insert((row, col), new_building.clone());
 
-                        match self.tilelist.get_mut(&(row, col)) {
+                        match self.tilelist.get_mut((row, col)) {
                             Some(tile) => tile.set_building(new_building),
                             None => warn!("WARNING: no tile at ({row}, {col})"),
                         }
@@ -853,24 +1126,68 @@ impl City {
      * Returns:
      *      Nothing, used to populate a city object from a file.
      */
-    pub fn create_city_from_file(city_path: &Path) -> Result<Self> {
-        let uncompressed_city = Self::open_and_uncompress_sc2_file(city_path)?;
-        let mut city = Self::new();
-
-        city.original_filename = city_path
+    pub fn create_city_from_file(city_path: &Path) -> Result<Self, ParseError> {
+        info!("reading file from {}...", city_path.to_string_lossy());
+        let raw_sc2_file = sc_util::open_file(city_path)?;
+        let file_name = city_path
             .file_name()
             .unwrap_or_default()
             .to_string_lossy()
             .into_owned();
+
+        Self::create_city(&raw_sc2_file, file_name)
+    }
+
+    /**
+     * Populates a city object from the contents of a .sc2 file.
+     * Args:
+     *      raw_sc2_file: Bytes of the .sc2 file.
+     * Returns:
+     *      The parsed city.
+     */
+    pub fn from_bytes(raw_sc2_file: &[u8]) -> Result<Self, ParseError> {
+        Self::create_city(raw_sc2_file, String::new())
+    }
+
+    /**
+     * Populates a city object by reading a .sc2 file from a reader until it's exhausted.
+     * Args:
+     *      reader: Source of the .sc2 file contents, e.g. stdin or an archive entry.
+     * Returns:
+     *      The parsed city.
+     */
+    pub fn from_reader(mut reader: impl Read) -> Result<Self, ParseError> {
+        let mut raw_sc2_file = vec![];
+
+        reader.read_to_end(&mut raw_sc2_file)?;
+
+        Self::from_bytes(&raw_sc2_file)
+    }
+
+    /**
+     * Parses the raw contents of a .sc2 file into a city.
+     * Args:
+     *      raw_sc2_file: Bytes of the .sc2 file.
+     *      original_filename: Name of the file the bytes came from, used as a fallback city name.
+     */
+    fn create_city(raw_sc2_file: &[u8], original_filename: String) -> Result<Self, ParseError> {
+        let uncompressed_city = Self::uncompress_sc2_data(raw_sc2_file)?;
+        let mut city = Self::new();
+
+        city.original_filename = original_filename;
         city.name_city(&uncompressed_city);
+        city.parse_labels(uncompressed_city.xlab());
         city.create_minimaps(&uncompressed_city);
         city.create_tilelist(&uncompressed_city);
+        city.create_underground_networks();
         city.parse_misc(uncompressed_city.misc());
+        city.summarize_bonds();
+        city.summarize_budget();
         city.find_buildings(&uncompressed_city)?;
-        city.parse_labels(uncompressed_city.xlab());
         city.parse_microsim(uncompressed_city.xmic());
         city.parse_things(uncompressed_city.xthg());
         city.parse_graphs(uncompressed_city.xgrp());
+        city.raw_chunks = uncompressed_city.raw_chunks();
 
         // Check for scenario.
         if uncompressed_city.text().is_empty()
@@ -880,7 +1197,7 @@ impl City {
             return Ok(city);
         }
 
-        city.parse_scenario(&uncompressed_city);
+        city.parse_scenario(&uncompressed_city)?;
         Ok(city)
     }
 
@@ -902,26 +1219,24 @@ impl City {
             city_name = file_name.join(".").to_uppercase();
         }
 
-        self.city_name = city_name;
-        log::debug!("city name is {:?} before truncating", self.city_name);
-        self.city_name.truncate(31);
+        log::debug!("city name is {:?} before truncating", city_name);
+        // Names decoded from CNAM can hold non-ASCII characters, so count characters rather than bytes.
+        self.city_name = city_name.chars().take(31).collect();
     }
 
     /**
-     * Handles opening and decompression of a city file.
+     * Handles decompression of a city file.
      * Args:
-     *      city_file_path: Path to the city file to be opened.
+     *      raw_sc2_file: Bytes of the city file.
      * Returns:
      *      Uncompressed city data ready for parsing into something more usable.
      *      This takes the form of a dictionary with the keys being the 4-letter chunk headers from the sc2 IFF file, and the values being the uncompressed raw binary data in bytearray from.
      */
-    fn open_and_uncompress_sc2_file(city_file_path: &Path) -> Result<ChunkList> {
-        info!("reading file from {}...", city_file_path.to_string_lossy());
-        let raw_sc2_file = sc_util::open_file(city_file_path);
+    fn uncompress_sc2_data(raw_sc2_file: &[u8]) -> Result<ChunkList, ParseError> {
         info!("reading city data chunks...");
-        let compressed_data = sc2p::chunk_input_serial(&raw_sc2_file, "sc2")?;
+        let compressed_data = sc2p::chunk_input_serial(raw_sc2_file, "sc2")?;
         info!("decompressing city data chunks...");
-        let uncompressed_data = sc2p::sc2_uncompress_input(compressed_data, "sc2");
+        let uncompressed_data = sc2p::sc2_uncompress_input(compressed_data, "sc2")?;
 
         Ok(uncompressed_data)
     }
@@ -937,25 +1252,23 @@ impl City {
         // This is the offset of the section that's being parsed from MISC.
         let parse_order = &MISC_PARSE_ORDER;
 
-        let mut handle_special = ([
-            "Population Graphs",
-            "Industry Graphs",
-            "Tile Counts",
-            "Bonds",
-            "Neighbours",
-            "Budget",
-            "Military Count",
-            "Paper List",
-            "News List",
-            "Extra",
-            "Ordinances",
-        ])
-        .into_iter()
-        .chain(SIMULATOR_SETTINGS_NAMES)
-        .chain(GAME_SETTING_NAME)
-        .chain(INVENTION_NAMES);
-
-        // Parse misc and generate city attributes.
+        self.misc = Misc::from_misc_data(misc_data);
+        self.date = self.misc.date();
+        self.city_age = self.misc.age();
+
+        debug!(
+            "City date: {} {} {}, age: {:?}",
+            self.date.day,
+            self.date.month_name(),
+            self.date.year,
+            self.city_age
+        );
+
+        self.inventions = Invention::from_inventions(&self.misc.inventions, self.date.year);
+        self.buildable_now = BuildableBuilding::buildable_now(&self.inventions);
+        self.newspapers = Newspapers::from_misc(&self.misc);
+
+        // Parse the sections that don't belong to Misc.
         for (k, v) in parse_order {
             let offset = *k as usize;
 
@@ -968,6 +1281,10 @@ impl City {
                         length,
                         misc_data,
                     );
+                    self.population_graph_labels = POPULATION_GRAPH_LABELS
+                        .iter()
+                        .map(|label| label.to_string())
+                        .collect();
                 }
 
                 "Industry Graphs" => {
@@ -978,6 +1295,10 @@ impl City {
                         length,
                         misc_data,
                     );
+                    self.industry_graph_labels = INDUSTRY_GRAPH_LABELS
+                        .iter()
+                        .map(|label| label.to_string())
+                        .collect();
                 }
 
                 "Tile Counts" => {
@@ -991,30 +1312,8 @@ impl City {
                     }
                 }
 
-                "Bonds" | "Ordinances" => {
-                    // Handled along with the budget.
-                }
-
                 "Neighbours" => {
-                    // Calculate their offsets. 64 = 4 neighbours at 4 x 4B entries each
-
-                    for start_offset in (offset..(offset + 64)).step_by(16) {
-                        // 16 = 4 entries x 4B per entry.
-                        let mut neighbour = Neighbour::default();
-
-                        for x in (start_offset..(start_offset + 16)).step_by(4) {
-                            let index = ((x + 8) % 16) / 4;
-
-                            neighbour.write(
-                                index,
-                                sc_util::parse_int32(
-                                    misc_data[x..(x + 4)].try_into().expect("should be 4 bytes"),
-                                ),
-                            );
-                        }
-
-                        self.neighbor_info.push(neighbour);
-                    }
+                    self.neighbours = Neighbours::from_misc_data(misc_data, offset);
                 }
 
                 "Budget" => {
@@ -1023,107 +1322,6 @@ impl City {
                     self.budget = Some(budget);
                 }
 
-                "Military Count" => {
-                    let num_items = 16;
-
-                    for (idx, x) in (offset..(offset + num_items * 4)).step_by(4).enumerate() {
-                        let key = format!("{v}|{idx}");
-
-                        self.city_attributes.insert(
-                            key,
-                            sc_util::parse_int32(
-                                misc_data[x..(x + 4)].try_into().expect("should be 4 bytes"),
-                            ),
-                        );
-                    }
-                }
-
-                "Paper List" => {
-                    let num_items = 6 * 5;
-
-                    for (idx, x) in (offset..(offset + num_items * 4)).step_by(4).enumerate() {
-                        let key = format!("{v}|{idx}");
-
-                        self.city_attributes.insert(
-                            key,
-                            sc_util::parse_int32(
-                                misc_data[x..(x + 4)].try_into().expect("should be 4 bytes"),
-                            ),
-                        );
-                    }
-                }
-
-                "News List" => {
-                    let num_items = 9 * 6;
-
-                    for (idx, x) in (offset..(offset + num_items * 4)).step_by(4).enumerate() {
-                        let key = format!("{v}|{idx}");
-
-                        self.city_attributes.insert(
-                            key,
-                            sc_util::parse_int32(
-                                misc_data[x..(x + 4)].try_into().expect("should be 4 bytes"),
-                            ),
-                        );
-                    }
-                }
-
-                "Extra" => {
-                    for (idx, x) in (offset..4800).step_by(4).enumerate() {
-                        let key = format!("{v}|{idx}");
-
-                        self.city_attributes.insert(
-                            key,
-                            sc_util::parse_int32(
-                                misc_data[x..(x + 4)].try_into().expect("should be 4 bytes"),
-                            ),
-                        );
-                    }
-                }
-
-                value if SIMULATOR_SETTINGS_NAMES.contains(&value) => {
-                    self.simulator_settings.insert(
-                        v.to_string(),
-                        sc_util::parse_int32(
-                            misc_data[offset..(offset + 4)]
-                                .try_into()
-                                .expect("should be 4 bytes"),
-                        ),
-                    );
-                }
-
-                value if GAME_SETTING_NAME.contains(&value) => {
-                    self.game_settings.insert(
-                        v.to_string(),
-                        sc_util::parse_int32(
-                            misc_data[offset..(offset + 4)]
-                                .try_into()
-                                .expect("should be 4 bytes"),
-                        ),
-                    );
-                }
-
-                value if INVENTION_NAMES.contains(&value) => {
-                    self.inventions.insert(
-                        v.to_string(),
-                        sc_util::parse_int32(
-                            misc_data[offset..(offset + 4)]
-                                .try_into()
-                                .expect("should be 4 bytes"),
-                        ),
-                    );
-                }
-
-                v if !handle_special.any(|item| v == item) => {
-                    let value = sc_util::parse_int32(
-                        misc_data[offset..(offset + 4)]
-                            .try_into()
-                            .expect("should be 4 bytes"),
-                    );
-
-                    self.city_attributes.insert(v.to_string(), value);
-                }
-
                 _ => {
                     // Fallthrough, this should never, ever, be hit.
                     error!("MISC is missing something! k: {}, v: {}", k, v);
@@ -1172,17 +1370,337 @@ impl City {
         output
     }
 
+    /**
+     * The inverse of misc_uninterleave_data(), writes the interleaved values back into MISC.
+     * Args:
+     *      keys (): list of keys representing the data we want to write.
+     *      offset (int): Offset into MISC where the segment we want to interleave starts.
+     *      length (int): Total length of the section.
+     *      values: The uninterleaved data, keyed by the entries in keys.
+     *      misc_data: MISC section that the data is written into.
+     */
+    fn misc_interleave_data(
+        keys: &[&str],
+        offset: usize,
+        length: usize,
+        values: &HashMap<String, Vec<i32>>,
+        misc_data: &mut [u8],
+    ) {
+        let num_keys = keys.len();
+
+        for (idx, local_offset) in (offset..(offset + length)).step_by(4).enumerate() {
+            let value = values
+                .get(keys[idx % num_keys])
+                .and_then(|series| series.get(idx / num_keys))
+                .copied()
+                .unwrap_or_default();
+
+            sc_util::write_int32(misc_data, local_offset, value);
+        }
+    }
+
+    /**
+     * Serializes the city into the contents of a .sc2 file.
+     * Scenario data (TEXT, SCEN and PICT) and unknown chunks are written back as they were read.
+     * Returns:
+     *      Bytes of a .sc2 file that can be loaded by the game.
+     */
+    pub fn write_city_to_bytes(&self) -> Vec<u8> {
+        info!("serializing city data chunks...");
+        let uncompressed_data = self.create_chunk_list();
+        info!("compressing city data chunks...");
+        let compressed_data = sc2p::sc2_compress_output(uncompressed_data);
+
+        sc2p::chunk_output_serial(&compressed_data)
+    }
+
+    /**
+     * Writes the city to a .sc2 file.
+     * Args:
+     *      city_path: Path of the file to create.
+     */
+    pub fn write_city_to_file(&self, city_path: &Path) -> io::Result<()> {
+        let raw_sc2_file = self.write_city_to_bytes();
+
+        info!("writing file to {}...", city_path.to_string_lossy());
+        fs::write(city_path, raw_sc2_file)?;
+
+        Ok(())
+    }
+
+    /**
+     * Converts the city back into uncompressed chunks, the inverse of the various parse functions.
+     * Returns:
+     *      Uncompressed city data, ready to be compressed.
+     */
+    fn create_chunk_list(&self) -> ChunkList {
+        let mut chunk_list = ChunkList::default();
+
+        chunk_list.set("CNAM", &self.write_city_name());
+        chunk_list.set("MISC", &self.write_misc());
+        self.write_tilelist(&mut chunk_list);
+        chunk_list.set("XBLD", &self.write_buildings());
+        self.write_minimaps(&mut chunk_list);
+        chunk_list.set("XLAB", &self.write_labels());
+        chunk_list.set("XMIC", &self.write_microsim());
+        chunk_list.set("XTHG", &self.write_things());
+        chunk_list.set("XGRP", &self.write_graphs());
+
+        for raw_chunk in &self.raw_chunks {
+            chunk_list.add_raw_chunk(raw_chunk);
+        }
+
+        chunk_list
+    }
+
+    /**
+     * Creates the CNAM segment, a length prefixed and zero padded name.
+     * Returns:
+     *      32 bytes of CNAM data.
+     */
+    fn write_city_name(&self) -> Vec<u8> {
+        let mut cnam = vec![0u8; 32];
+        let name: Vec<u8> = self
+            .city_name
+            .chars()
+            .take(31)
+            .map(|c| u8::try_from(c).unwrap_or(b'?'))
+            .collect();
+
+        cnam[0] = name.len() as u8;
+        cnam[1..(1 + name.len())].copy_from_slice(&name);
+
+        cnam
+    }
+
+    /**
+     * Creates the ALTM, XTER, XZON, XUND, XTXT and XBIT segments from the tilelist.
+     * Args:
+     *      chunk_list: Uncompressed chunks to write the segments into.
+     */
+    fn write_tilelist(&self, chunk_list: &mut ChunkList) {
+        let tile_count = self.city_size * self.city_size;
+        let mut altm = vec![0u8; tile_count * 2];
+        let mut xter = vec![0u8; tile_count];
+        let mut xzon = vec![0u8; tile_count];
+        let mut xund = vec![0u8; tile_count];
+        let mut xtxt = vec![0u8; tile_count];
+        let mut xbit = vec![0u8; tile_count];
+
+        for row in 0..self.city_size {
+            for col in 0..self.city_size {
+                let tile_idx = row * self.city_size + col;
+                let Some(tile) = self.tilelist.get((row, col)) else {
+                    warn!("WARNING: no tile at ({row}, {col})");
+                    continue;
+                };
+
+                let altm_bits = (tile.altitude_tunnel() << 8)
+                    | ((tile.is_water() as u32) << 7)
+                    | (tile.altitude_unknown() << 5)
+                    | tile.altitude();
+
+                altm[(tile_idx * 2)..(tile_idx * 2 + 2)]
+                    .copy_from_slice(&(altm_bits as u16).to_be_bytes());
+                xter[tile_idx] = *tile.terrain();
+                xzon[tile_idx] = (tile.zone_corners().bits() << 4) | tile.zone().to_u8();
+                xund[tile_idx] = *tile.underground();
+                xtxt[tile_idx] = tile.text_pointer() as u8;
+                xbit[tile_idx] = tile
+                    .bit_flags()
+                    .as_ref()
+                    .map(|flags| flags.to_byte())
+                    .unwrap_or_default();
+            }
+        }
+
+        chunk_list.set("ALTM", &altm);
+        chunk_list.set("XTER", &xter);
+        chunk_list.set("XZON", &xzon);
+        chunk_list.set("XUND", &xund);
+        chunk_list.set("XTXT", &xtxt);
+        chunk_list.set("XBIT", &xbit);
+    }
+
+    /**
+     * Finds the building that occupies a tile.
+     * Args:
+     *      tile_coords: coordinates of the tile.
+     * Returns:
+     *      The building, network piece or groundcover on the tile, if any.
+     */
+    fn building_at(&self, tile_coords: (usize, usize)) -> Option<&Arc<Building>> {
+        let tile_building = self
+            .tilelist
+            .get(tile_coords)
+            .and_then(|tile| tile.building().as_ref());
+
+        // 2x2 highway pieces are only tracked in the networks.
+        tile_building.or_else(|| self.networks.get(&tile_coords))
+    }
+
+    /**
+     * Creates the XBLD segment from the raw building ids of the tiles. Buildings are derived from XBLD, so tiles
+     * that aren't part of a building, like holes or orphaned pieces, keep their id as well.
+     * Returns:
+     *      One building id per tile.
+     */
+    fn write_buildings(&self) -> Vec<u8> {
+        self.tilelist.map(Tile::building_id).cells().to_vec()
+    }
+
+    /**
+     * Creates the 8 minimap segments.
+     * Args:
+     *      chunk_list: Uncompressed chunks to write the segments into.
+     */
+    fn write_minimaps(&self, chunk_list: &mut ChunkList) {
+        let minimaps = [
+            ("XTRF", &self.traffic),
+            ("XPLT", &self.pollution),
+            ("XVAL", &self.value),
+            ("XCRM", &self.crime),
+            ("XPLC", &self.police),
+            ("XFIR", &self.fire),
+            ("XPOP", &self.density),
+            ("XROG", &self.growth),
+        ];
+
+        // The grids are stored in the same row-major order as the segments.
+        for (chunk_id, minimap) in minimaps {
+            chunk_list.set(chunk_id, minimap.grid().cells());
+        }
+    }
+
+    /**
+     * Creates the XLAB segment, 256 labels of 25 bytes each.
+     * Returns:
+     *      Raw XLAB segment.
+     */
+    fn write_labels(&self) -> Vec<u8> {
+        let mut xlab_segment = vec![0u8; 256 * 25];
+
+        for (label_id, label) in self.labels.iter().take(256).enumerate() {
+            let raw_label = &mut xlab_segment[(label_id * 25)..((label_id + 1) * 25)];
+            let label_bytes: Vec<u8> = label
+                .chars()
+                .take(24)
+                .map(|c| u8::try_from(c).unwrap_or(b'?'))
+                .collect();
+
+            raw_label[0] = label_bytes.len() as u8;
+            raw_label[1..(1 + label_bytes.len())].copy_from_slice(&label_bytes);
+        }
+
+        xlab_segment
+    }
+
+    /**
+     * Creates the XMIC segment from the raw micro simulation data.
+     * Returns:
+     *      Raw XMIC segment.
+     */
+    fn write_microsim(&self) -> Vec<u8> {
+        self.microsim_state.concat()
+    }
+
+    /**
+     * Creates the XTHG segment.
+     * Returns:
+     *      Raw XTHG segment.
+     */
+    fn write_things(&self) -> Vec<u8> {
+        self.things.iter().flat_map(Thing::to_bytes).collect()
+    }
+
+    /**
+     * Creates the XGRP segment.
+     * Returns:
+     *      Raw XGRP segment.
+     */
+    fn write_graphs(&self) -> Vec<u8> {
+        GRAPH_WINDOW_GRAPHS
+            .iter()
+            .flat_map(|graph_name| {
+                self.graphs
+                    .get(*graph_name)
+                    .map(Graph::to_bytes)
+                    .unwrap_or_else(|| vec![0u8; 52 * 4])
+            })
+            .collect()
+    }
+
+    /**
+     * Creates the MISC segment from the misc values, graphs, neighbours and budget.
+     * This is the inverse of parse_misc().
+     * Returns:
+     *      The 4800 bytes of the MISC segment.
+     */
+    fn write_misc(&self) -> Vec<u8> {
+        let mut misc_data = vec![0u8; 4800];
+
+        self.misc.write_misc_data(&mut misc_data);
+
+        for (k, v) in &MISC_PARSE_ORDER {
+            let offset = *k as usize;
+
+            match *v {
+                "Population Graphs" => {
+                    Self::misc_interleave_data(
+                        &POPULATION_GRAPH_NAMES,
+                        offset,
+                        240,
+                        &self.population_graphs,
+                        &mut misc_data,
+                    );
+                }
+
+                "Industry Graphs" => {
+                    Self::misc_interleave_data(
+                        &INDUSTRY_GRAPH_NAMES,
+                        offset,
+                        132,
+                        &self.industry_graphs,
+                        &mut misc_data,
+                    );
+                }
+
+                "Tile Counts" => {
+                    for (x, count) in self.building_count.iter().take(256).enumerate() {
+                        sc_util::write_int32(&mut misc_data, offset + x * 4, *count);
+                    }
+                }
+
+                "Neighbours" => {
+                    self.neighbours.write_misc_data(&mut misc_data, offset);
+                }
+
+                "Budget" => {
+                    if let Some(budget) = &self.budget {
+                        budget.write_misc_data(&mut misc_data);
+                    }
+                }
+
+                _ => {
+                    error!("MISC is missing something! k: {}, v: {}", k, v);
+                }
+            }
+        }
+
+        misc_data
+    }
+
     fn describe_tile(&self, tile_coords: (usize, usize)) -> Option<String> {
-        let tile = self.tilelist.get(&tile_coords)?;
+        let tile = self.tilelist.get(tile_coords)?;
 
-        let terr = int_to_bitstring(*tile.terrain() as u32, 0);
+        let terr = format!("{:b}", tile.terrain());
         let b_id = match &tile.building() {
             Some(building) => format!("{:#04x}", building.building_id),
             None => String::from("null"),
         };
 
         let sign_text = tile
-            .text()
+            .text(self)
             .map(|text| format!(", Sign: {:?}", text))
             .unwrap_or_default();
 
@@ -1192,7 +1710,7 @@ Altitude:
     tunnel: {}, water: {}, unknown: {}, altitude: {}
 Terrain: {}
 Buildings:
-    id: {}, corners {}, zone: {}, underground: {}
+    id: {}, corners {:04b}, zone: {:?}, underground: {}
 Text pointer: {}{}
 Flags: {:?}
 Minimap:
@@ -1205,7 +1723,7 @@ Minimap:
             tile.altitude(),
             terr,
             b_id,
-            tile.zone_corners(),
+            tile.zone_corners().bits(),
             tile.zone(),
             tile.underground(),
             tile.text_pointer(),
@@ -1222,3 +1740,267 @@ Minimap:
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_rng::Rng;
+    use super::*;
+
+    const SEED_EMPTY: &[u8] = include_bytes!("../../fuzz/corpus/city/seed_empty.sc2");
+    const SEEDS: [&[u8]; 4] = [
+        SEED_EMPTY,
+        include_bytes!("../../fuzz/corpus/city/seed_buildings.sc2"),
+        include_bytes!("../../fuzz/corpus/city/seed_mac.sc2"),
+        include_bytes!("../../fuzz/corpus/city/seed_latin1_name.sc2"),
+    ];
+
+    fn uncompress(raw_sc2_file: &[u8]) -> ChunkList {
+        let compressed_data =
+            sc2p::chunk_input_serial(raw_sc2_file, "sc2").expect("city should parse");
+
+        sc2p::sc2_uncompress_input(compressed_data, "sc2").expect("city should uncompress")
+    }
+
+    /**
+     * Parses a city, writes it back and compares every uncompressed chunk.
+     * Args:
+     *      raw_sc2_file (bytes): city to check.
+     */
+    fn assert_round_trip(raw_sc2_file: &[u8]) {
+        let original = uncompress(raw_sc2_file);
+        let city = City::from_bytes(raw_sc2_file).expect("city should parse");
+        let written = uncompress(&city.write_city_to_bytes());
+
+        let ids = |chunk_list: &ChunkList| -> Vec<String> {
+            chunk_list.chunks().map(|(id, _)| id.to_owned()).collect()
+        };
+        assert_eq!(ids(&written), ids(&original));
+
+        for ((chunk_id, written_data), (_, original_data)) in
+            written.chunks().zip(original.chunks())
+        {
+            let first_difference = written_data
+                .iter()
+                .zip(original_data)
+                .position(|(written_byte, original_byte)| written_byte != original_byte);
+
+            assert!(
+                written_data == original_data,
+                "{chunk_id} differs, {} bytes written, {} read, first difference at {first_difference:?}",
+                written_data.len(),
+                original_data.len()
+            );
+        }
+    }
+
+    /**
+     * Replaces the contents of a seed with random data that still parses, and adds scenario and unknown chunks.
+     * Args:
+     *      raw_sc2_file (bytes): seed to start from.
+     *      rng: source of the random data.
+     */
+    fn mutate(raw_sc2_file: &[u8], rng: &mut Rng) -> Vec<u8> {
+        let mut chunk_list = uncompress(raw_sc2_file);
+        let tile_count = 128 * 128;
+
+        for (chunk_id, len) in [
+            ("ALTM", tile_count * 2),
+            ("XTER", tile_count),
+            ("XZON", tile_count),
+            ("XUND", tile_count),
+            ("XTXT", tile_count),
+            ("XBIT", tile_count),
+            ("XTRF", 64 * 64),
+            ("XPLT", 64 * 64),
+            ("XVAL", 64 * 64),
+            ("XCRM", 64 * 64),
+            ("XPLC", 32 * 32),
+            ("XFIR", 32 * 32),
+            ("XPOP", 32 * 32),
+            ("XROG", 32 * 32),
+            ("XMIC", 1200),
+            ("XTHG", 480),
+            ("XGRP", 3328),
+        ] {
+            chunk_list.set(chunk_id, &rng.bytes(len));
+        }
+
+        let mut misc_data = rng.bytes(4800);
+        sc_util::write_int32(&mut misc_data, 0x0008, rng.below(4) as i32);
+        chunk_list.set("MISC", &misc_data);
+
+        let building_ids: Vec<u8> = (0..=u8::MAX)
+            .filter(|building_id| Building::new(*building_id, (0, 0)).is_some())
+            .collect();
+        let xbld: Vec<u8> = (0..tile_count)
+            .map(|_| building_ids[rng.below(building_ids.len())])
+            .collect();
+        chunk_list.set("XBLD", &xbld);
+
+        let mut xlab = vec![0u8; 256 * 25];
+        for raw_label in xlab.chunks_exact_mut(25) {
+            let label_len = rng.below(25);
+
+            raw_label[0] = label_len as u8;
+            for byte in &mut raw_label[1..(1 + label_len)] {
+                *byte = 1 + rng.below(255) as u8;
+            }
+        }
+        chunk_list.set("XLAB", &xlab);
+
+        let mut cnam = vec![0u8; 32];
+        cnam[0] = 31;
+        for byte in &mut cnam[1..] {
+            *byte = 1 + rng.below(255) as u8;
+        }
+        chunk_list.set("CNAM", &cnam);
+
+        let mut pict = vec![0x80, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00];
+        pict.extend([rng.next() as u8, rng.next() as u8, 0xFF]);
+        let unknown_len = rng.below(100);
+        for (chunk_id, data) in [
+            ("TEXT", [&[0x80, 0x00, 0x00, 0x00][..], b"Title\r"].concat()),
+            (
+                "TEXT",
+                [&[0x81, 0x00, 0x00, 0x00][..], b"Description"].concat(),
+            ),
+            ("SCEN", rng.bytes(52)),
+            ("PICT", pict),
+            ("ZZZZ", rng.bytes(unknown_len)),
+        ] {
+            chunk_list.add_raw_chunk(&RawChunk {
+                id: chunk_id.to_owned(),
+                data,
+            });
+        }
+
+        sc2p::chunk_output_serial(&sc2p::sc2_compress_output(chunk_list))
+    }
+
+    #[test]
+    fn tile_text() {
+        let mut chunk_list = uncompress(SEED_EMPTY);
+
+        let mut xlab = vec![0u8; 256 * 25];
+        xlab[3 * 25] = 6;
+        xlab[(3 * 25 + 1)..(3 * 25 + 7)].copy_from_slice(b"Harbor");
+        chunk_list.set("XLAB", &xlab);
+
+        let mut xtxt = vec![0u8; 128 * 128];
+        xtxt[2 * 128 + 5] = 3;
+        chunk_list.set("XTXT", &xtxt);
+
+        let raw_sc2_file = sc2p::chunk_output_serial(&sc2p::sc2_compress_output(chunk_list));
+        let city = City::from_bytes(&raw_sc2_file).expect("city should parse");
+
+        assert_eq!(city.tilelist()[(2, 5)].text(&city), Some("Harbor"));
+        assert_eq!(city.tilelist()[(2, 6)].text(&city), None);
+    }
+
+    #[test]
+    fn write_round_trip_seeds() {
+        for seed in SEEDS {
+            assert_round_trip(seed);
+        }
+    }
+
+    #[test]
+    fn write_round_trip_mutated() {
+        let mut rng = Rng(0x5C2_0000_2000);
+
+        for seed in SEEDS {
+            for _ in 0..4 {
+                assert_round_trip(&mutate(seed, &mut rng));
+            }
+        }
+    }
+
+    /**
+     * Replaces the CNAM chunk of a city file.
+     * Args:
+     *      raw_sc2_file (bytes): city file with a 32 byte CNAM chunk.
+     *      name (bytes): raw name, at most 31 bytes.
+     */
+    fn with_cnam(raw_sc2_file: &[u8], name: &[u8]) -> Vec<u8> {
+        let mut raw_sc2_file = raw_sc2_file.to_vec();
+        let offset = raw_sc2_file
+            .windows(4)
+            .position(|window| window == b"CNAM")
+            .expect("seed should have a CNAM chunk")
+            + 8;
+
+        raw_sc2_file[offset..(offset + 32)].fill(0);
+        raw_sc2_file[offset] = name.len() as u8;
+        raw_sc2_file[(offset + 1)..(offset + 1 + name.len())].copy_from_slice(name);
+
+        raw_sc2_file
+    }
+
+    #[test]
+    fn non_ascii_city_name() {
+        let city =
+            City::from_bytes(&with_cnam(SEED_EMPTY, &[0xE9; 31])).expect("seed should parse");
+
+        assert_eq!(city.city_name(), "\u{e9}".repeat(31));
+        assert_eq!(city.write_city_name()[1..], [0xE9; 31]);
+    }
+
+    /// Decodes a 2x2 city from the given ALTM words and XZON bytes.
+    fn decode_tiles(altm: [u16; 4], xzon: [u8; 4]) -> City {
+        let mut raw_sc2_data = ChunkList::default();
+        let altm: Vec<u8> = altm.iter().flat_map(|word| word.to_be_bytes()).collect();
+
+        raw_sc2_data.set("ALTM", &altm);
+        raw_sc2_data.set("XZON", &xzon);
+        for id in ["XTER", "XBLD", "XUND", "XTXT", "XBIT"] {
+            raw_sc2_data.set(id, &[0; 4]);
+        }
+
+        let mut city = City::new();
+        city.city_size = 2;
+        city.create_tilelist(&raw_sc2_data);
+
+        city
+    }
+
+    #[test]
+    fn decodes_altm() {
+        let city = decode_tiles([0x0000, 0x03E5, 0x0047, 0xFF9F], [0; 4]);
+        // (tunnel, water, unknown, altitude) of every tile.
+        let expected = [
+            (0, false, 0, 0),
+            (3, true, 3, 5),
+            (0, false, 2, 7),
+            (255, true, 0, 31),
+        ];
+
+        for (index, (tunnel, water, unknown, altitude)) in expected.into_iter().enumerate() {
+            let tile = &city.tilelist[(index / 2, index % 2)];
+
+            assert_eq!(tile.altitude_tunnel(), tunnel);
+            assert_eq!(tile.is_water(), water);
+            assert_eq!(tile.altitude_unknown(), unknown);
+            assert_eq!(tile.altitude(), altitude);
+        }
+    }
+
+    #[test]
+    fn decodes_xzon() {
+        let city = decode_tiles([0; 4], [0x00, 0x81, 0x29, 0xFC]);
+        let expected = [
+            (Corners::NONE, Zone::None),
+            (Corners::LEFT, Zone::LightResidential),
+            (Corners::RIGHT, Zone::Seaport),
+            (Corners::from_bits(0b1111), Zone::Unknown(12)),
+        ];
+
+        for (index, (corners, zone)) in expected.into_iter().enumerate() {
+            let tile = &city.tilelist[(index / 2, index % 2)];
+
+            assert_eq!(tile.zone_corners(), corners);
+            assert_eq!(tile.zone(), zone);
+        }
+
+        assert!(city.tilelist[(0, 1)].zone_corners().left());
+    }
+}
@@ -0,0 +1,57 @@
+// Serialization of maps that are keyed by tile coordinates.
+// Binary formats keep the (x, y) tuple keys, human readable formats like JSON only support string keys,
+// so there the keys are written as "x,y".
+
+use std::collections::HashMap;
+
+use serde::de::Error as DeError;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub fn serialize<S, V>(value: &HashMap<(usize, usize), V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    V: Serialize,
+{
+    if !serializer.is_human_readable() {
+        return value.serialize(serializer);
+    }
+
+    let mut map = serializer.serialize_map(Some(value.len()))?;
+
+    for ((x, y), item) in value {
+        map.serialize_entry(&format!("{x},{y}"), item)?;
+    }
+
+    map.end()
+}
+
+pub fn deserialize<'de, D, V>(deserializer: D) -> Result<HashMap<(usize, usize), V>, D::Error>
+where
+    D: Deserializer<'de>,
+    V: Deserialize<'de>,
+{
+    if !deserializer.is_human_readable() {
+        return HashMap::deserialize(deserializer);
+    }
+
+    HashMap::<String, V>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(key, item)| Ok((parse_key(&key).map_err(D::Error::custom)?, item)))
+        .collect()
+}
+
+fn parse_key(key: &str) -> Result<(usize, usize), String> {
+    let (x, y) = key
+        .split_once(',')
+        .ok_or_else(|| format!("invalid coordinate key {key:?}"))?;
+
+    let parse = |value: &str| {
+        value
+            .trim()
+            .parse::<usize>()
+            .map_err(|err| format!("invalid coordinate key {key:?}: {err}"))
+    };
+
+    Ok((parse(x)?, parse(y)?))
+}
@@ -1,13 +1,24 @@
 use std::fmt::Display;
 
-use super::sc_util::parse_int32;
-use serde::Serialize;
+use super::calendar::Date;
+use super::sc_util::{int32s_to_bytes, parse_int32};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Default, Debug, Serialize)]
+#[derive(Default, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct Graph {
     one_year: [i32; 12],
     ten_years: [i32; 20],
     hundred_years: [i32; 20],
+    /// Month of every point in one_year, oldest first.
+    #[serde(default)]
+    one_year_dates: Vec<Date>,
+    /// Month of every point in ten_years, 6 months apart.
+    #[serde(default)]
+    ten_years_dates: Vec<Date>,
+    /// Month of every point in hundred_years, 5 years apart.
+    #[serde(default)]
+    hundred_years_dates: Vec<Date>,
 }
 
 impl Graph {
@@ -52,8 +63,61 @@ impl Graph {
             one_year,
             ten_years,
             hundred_years,
+            ..Default::default()
         }
     }
+
+    /**
+     * Dates the points of every series, the newest point being the current month.
+     * Args:
+     *      current: current in-game date.
+     */
+    pub fn set_dates(&mut self, current: Date) {
+        let dates = |points: usize, step: i32| -> Vec<Date> {
+            (0..points as i32)
+                .rev()
+                .map(|age| current.months_before(age * step))
+                .collect()
+        };
+
+        self.one_year_dates = dates(self.one_year.len(), 1);
+        self.ten_years_dates = dates(self.ten_years.len(), 6);
+        self.hundred_years_dates = dates(self.hundred_years.len(), 60);
+    }
+
+    pub fn one_year(&self) -> &[i32; 12] {
+        &self.one_year
+    }
+
+    pub fn ten_years(&self) -> &[i32; 20] {
+        &self.ten_years
+    }
+
+    pub fn hundred_years(&self) -> &[i32; 20] {
+        &self.hundred_years
+    }
+
+    pub fn one_year_dates(&self) -> &[Date] {
+        &self.one_year_dates
+    }
+
+    pub fn ten_years_dates(&self) -> &[Date] {
+        &self.ten_years_dates
+    }
+
+    pub fn hundred_years_dates(&self) -> &[Date] {
+        &self.hundred_years_dates
+    }
+
+    /// Converts the graph back into its raw representation in XGRP.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut raw_graph = int32s_to_bytes(&self.one_year);
+
+        raw_graph.append(&mut int32s_to_bytes(&self.ten_years));
+        raw_graph.append(&mut int32s_to_bytes(&self.hundred_years));
+
+        raw_graph
+    }
 }
 
 impl Display for Graph {
@@ -0,0 +1,149 @@
+// Dense storage for per-tile data like the tilelist and the minimaps.
+
+use std::ops::{Index, IndexMut};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Fixed size grid stored row-major, so the cell at (row, col) is cells[row * width + col].
+/// This is the same layout the game uses for its map segments.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(try_from = "GridData<T>")]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Unchecked grid as it's read by serde, converted into a Grid once the dimensions are validated.
+#[derive(Deserialize)]
+struct GridData<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> TryFrom<GridData<T>> for Grid<T> {
+    type Error = String;
+
+    fn try_from(value: GridData<T>) -> Result<Self, Self::Error> {
+        let GridData {
+            width,
+            height,
+            cells,
+        } = value;
+
+        Self::from_cells(width, height, cells)
+            .ok_or_else(|| format!("grid of {width}x{height} cells can't hold the given cells"))
+    }
+}
+
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+        Self {
+            width: 0,
+            height: 0,
+            cells: vec![],
+        }
+    }
+}
+
+impl<T: Clone + Default> Grid<T> {
+    /**
+     * Creates a grid with every cell set to the default value.
+     * Args:
+     *      width (int): number of columns.
+     *      height (int): number of rows.
+     */
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![T::default(); width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /**
+     * Creates a grid from row-major cells.
+     * Args:
+     *      width (int): number of columns.
+     *      height (int): number of rows.
+     *      cells: width * height cells, one row after another.
+     * Returns:
+     *      The grid, or None if the number of cells doesn't match the dimensions.
+     */
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        (width.checked_mul(height)? == cells.len()).then_some(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn cell_index(&self, (row, col): (usize, usize)) -> Option<usize> {
+        (row < self.height && col < self.width).then(|| row * self.width + col)
+    }
+
+    pub fn get(&self, coords: (usize, usize)) -> Option<&T> {
+        self.cell_index(coords).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, coords: (usize, usize)) -> Option<&mut T> {
+        self.cell_index(coords).map(|index| &mut self.cells[index])
+    }
+
+    /// All cells, one row after another.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks_exact panics on a chunk size of 0, an empty grid has no rows either way.
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// Creates a grid of the same size by converting every cell.
+    pub fn map<U>(&self, convert: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(convert).collect(),
+        }
+    }
+
+    /// Iterates over all cells with their (row, col) coordinates.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| ((index / width, index % width), cell))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coords: (usize, usize)) -> &Self::Output {
+        self.get(coords)
+            .expect("coordinates should be inside the grid")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, coords: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(coords)
+            .expect("coordinates should be inside the grid")
+    }
+}
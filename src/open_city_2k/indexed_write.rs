@@ -5,6 +5,10 @@ pub trait IndexedWrite<T> {
     fn field_len() -> usize;
 }
 
+pub trait IndexedRead<T>: IndexedWrite<T> {
+    fn read(&self, index: usize) -> T;
+}
+
 pub struct IndexedWriter<'t, V, T>
 where
     T: IndexedWrite<V>,
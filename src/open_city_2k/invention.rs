@@ -0,0 +1,74 @@
+// Inventions from MISC, linked with the buildings they unlock.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::buildings;
+use super::misc::{Inventions, INVENTION_NAMES};
+
+/// An invention and whether it has been made yet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Invention {
+    /// Name of the invention, as in Inventions.
+    pub name: String,
+    /// True once the current year has reached year_invented.
+    pub invented: bool,
+    pub year_invented: i32,
+    /// Ids of the buildings that need this invention.
+    pub buildings: Vec<u8>,
+}
+
+impl Invention {
+    /**
+     * Lists all inventions.
+     * Args:
+     *      inventions: invention years from MISC.
+     *      current_year (int): current in-game year.
+     * Returns:
+     *      Every invention, in the order they are stored in MISC.
+     */
+    pub fn from_inventions(inventions: &Inventions, current_year: i32) -> Vec<Self> {
+        INVENTION_NAMES
+            .iter()
+            .zip(inventions.years())
+            .map(|(name, year_invented)| Self {
+                name: name.to_string(),
+                invented: year_invented <= current_year,
+                year_invented,
+                buildings: buildings::gated_by(name),
+            })
+            .collect()
+    }
+}
+
+/// A building that can be built with the current inventions.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct BuildableBuilding {
+    pub building_id: u8,
+    pub name: String,
+}
+
+impl BuildableBuilding {
+    /**
+     * Lists the buildings that can be built right now.
+     * Args:
+     *      inventions: all inventions, see Invention::from_inventions().
+     * Returns:
+     *      Every building that costs money to build or needs an invention, if that invention has been made.
+     */
+    pub fn buildable_now(inventions: &[Invention]) -> Vec<Self> {
+        let is_invented = |name: &str| {
+            inventions
+                .iter()
+                .any(|invention| invention.name == name && invention.invented)
+        };
+
+        buildings::buildable(is_invented)
+            .into_iter()
+            .map(|(building_id, name)| Self {
+                building_id,
+                name: name.to_string(),
+            })
+            .collect()
+    }
+}
@@ -1,5 +1,8 @@
-use serde::Serialize;
-use std::{collections::HashMap, fmt::Display};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+use super::grid::Grid;
 
 // Couldn't think of a better name, but this stores minimap info/simulation variables stores in:
 // XTRF, XPLT, XVAL, XCRM, XPLC, XFIR, XPOP, XROG.
@@ -8,24 +11,22 @@ const X64: [&str; 4] = ["XTRF", "XPLT", "XVAL", "XCRM"];
 #[allow(dead_code)]
 const X32: [&str; 4] = ["XPLC", "XFIR", "XPOP", "XROG"];
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct Minimap {
     name: String,
-    // #[serde(serialize_with = "serialize_cord_hash_map")]
-    data: HashMap<(usize, usize), u8>,
-    size: usize,
+    data: Grid<u8>,
 }
 
 impl Minimap {
     pub fn new(name: String, size: usize) -> Self {
-        let data = Default::default();
+        let data = Grid::new(size, size);
 
-        Self { name, size, data }
+        Self { name, data }
     }
 
     fn convert_xy(&self, key: (usize, usize)) -> (usize, usize) {
         let (x, y) = key;
-        let d = if self.size == 64 { 2 } else { 4 };
+        let d = if self.size() == 64 { 2 } else { 4 };
 
         (x / d, y / d)
     }
@@ -33,24 +34,36 @@ impl Minimap {
     pub fn get_scaled(&self, key: (usize, usize)) -> &u8 {
         let new_key = self.convert_xy(key);
 
-        &self.data[&new_key]
+        &self.data[new_key]
     }
 
     #[allow(dead_code)]
     pub fn set_scaled(&mut self, key: (usize, usize), item: u8) {
         let new_key = self.convert_xy(key);
 
-        self.data.insert(new_key, item);
+        self.data[new_key] = item;
     }
 
-    #[allow(dead_code)]
     pub fn set_item(&mut self, key: (usize, usize), value: u8) {
-        self.data.insert(key, value);
+        self.data[key] = value;
     }
 
-    #[allow(dead_code)]
-    fn get_item(&self, key: (usize, usize)) -> &u8 {
-        &self.data[&key]
+    pub fn get_item(&self, key: (usize, usize)) -> &u8 {
+        &self.data[key]
+    }
+
+    /// All values of the minimap as a size x size grid.
+    pub fn grid(&self) -> &Grid<u8> {
+        &self.data
+    }
+
+    /// Width and height of the minimap, taken from its grid.
+    pub fn size(&self) -> usize {
+        self.data.width()
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
     }
 }
 
@@ -58,8 +71,8 @@ impl Display for Minimap {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "{}", self.name)?;
 
-        for _ in 0..self.size {
-            for y in 0..self.size {
+        for _ in 0..self.size() {
+            for y in 0..self.size() {
                 write!(f, "{y}")?;
             }
 
@@ -0,0 +1,595 @@
+// Typed view of the scalar values and lists in MISC. Graphs, tile counts, neighbours and the budget are parsed
+// separately by the city.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::calendar::{CityAge, Date};
+use super::sc_util::{parse_int32, write_int32};
+
+/// Whether the city is still being terraformed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum GameMode {
+    TerrainEditor,
+    #[default]
+    City,
+    Unknown(i32),
+}
+
+impl GameMode {
+    pub fn from_i32(value: i32) -> Self {
+        match value {
+            0 => Self::TerrainEditor,
+            1 => Self::City,
+            _ => Self::Unknown(value),
+        }
+    }
+
+    pub fn to_i32(self) -> i32 {
+        match self {
+            Self::TerrainEditor => 0,
+            Self::City => 1,
+            Self::Unknown(value) => value,
+        }
+    }
+}
+
+/// Direction the map is rotated to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Compass {
+    #[default]
+    North,
+    East,
+    South,
+    West,
+    Unknown(i32),
+}
+
+impl Compass {
+    pub fn from_i32(value: i32) -> Self {
+        match value {
+            0 => Self::North,
+            1 => Self::East,
+            2 => Self::South,
+            3 => Self::West,
+            _ => Self::Unknown(value),
+        }
+    }
+
+    pub fn to_i32(self) -> i32 {
+        match self {
+            Self::North => 0,
+            Self::East => 1,
+            Self::South => 2,
+            Self::West => 3,
+            Self::Unknown(value) => value,
+        }
+    }
+
+    /// Number of quarter turns the map is rotated by, None for unknown values.
+    pub fn rotation(self) -> Option<usize> {
+        match self {
+            Self::Unknown(_) => None,
+            compass => Some(compass.to_i32() as usize),
+        }
+    }
+}
+
+/// Difficulty the city was started with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum GameLevel {
+    #[default]
+    Easy,
+    Medium,
+    Hard,
+    Unknown(i32),
+}
+
+impl GameLevel {
+    pub fn from_i32(value: i32) -> Self {
+        match value {
+            0 => Self::Easy,
+            1 => Self::Medium,
+            2 => Self::Hard,
+            _ => Self::Unknown(value),
+        }
+    }
+
+    pub fn to_i32(self) -> i32 {
+        match self {
+            Self::Easy => 0,
+            Self::Medium => 1,
+            Self::Hard => 2,
+            Self::Unknown(value) => value,
+        }
+    }
+}
+
+/// Size class of the city, based on its population.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum CityStatus {
+    #[default]
+    Village,
+    Town,
+    City,
+    Capital,
+    Metropolis,
+    Megalopolis,
+    Unknown(i32),
+}
+
+impl CityStatus {
+    pub fn from_i32(value: i32) -> Self {
+        match value {
+            0 => Self::Village,
+            1 => Self::Town,
+            2 => Self::City,
+            3 => Self::Capital,
+            4 => Self::Metropolis,
+            5 => Self::Megalopolis,
+            _ => Self::Unknown(value),
+        }
+    }
+
+    pub fn to_i32(self) -> i32 {
+        match self {
+            Self::Village => 0,
+            Self::Town => 1,
+            Self::City => 2,
+            Self::Capital => 3,
+            Self::Metropolis => 4,
+            Self::Megalopolis => 5,
+            Self::Unknown(value) => value,
+        }
+    }
+}
+
+/// Zoom level of the city window, from furthest away to closest.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Zoom {
+    #[default]
+    Far,
+    Medium,
+    Close,
+    Closest,
+    Unknown(i32),
+}
+
+impl Zoom {
+    pub fn from_i32(value: i32) -> Self {
+        match value {
+            1 => Self::Far,
+            2 => Self::Medium,
+            3 => Self::Close,
+            4 => Self::Closest,
+            _ => Self::Unknown(value),
+        }
+    }
+
+    pub fn to_i32(self) -> i32 {
+        match self {
+            Self::Far => 1,
+            Self::Medium => 2,
+            Self::Close => 3,
+            Self::Closest => 4,
+            Self::Unknown(value) => value,
+        }
+    }
+}
+
+/// Simulation speed, as in the speed menu.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum GameSpeed {
+    #[default]
+    Paused,
+    Turtle,
+    Llama,
+    Cheetah,
+    AfricanSwallow,
+    Unknown(i32),
+}
+
+impl GameSpeed {
+    pub fn from_i32(value: i32) -> Self {
+        match value {
+            1 => Self::Paused,
+            2 => Self::Turtle,
+            3 => Self::Llama,
+            4 => Self::Cheetah,
+            5 => Self::AfricanSwallow,
+            _ => Self::Unknown(value),
+        }
+    }
+
+    pub fn to_i32(self) -> i32 {
+        match self {
+            Self::Paused => 1,
+            Self::Turtle => 2,
+            Self::Llama => 3,
+            Self::Cheetah => 4,
+            Self::AfricanSwallow => 5,
+            Self::Unknown(value) => value,
+        }
+    }
+}
+
+/// Names of the inventions, in the order they are stored in MISC.
+pub const INVENTION_NAMES: [&str; 17] = [
+    "gas_power",
+    "nuclear_power",
+    "solar_power",
+    "wind_power",
+    "microwave_power",
+    "fusion_power",
+    "airport",
+    "highways",
+    "buses",
+    "subways",
+    "water_treatment",
+    "desalinisation",
+    "plymouth",
+    "forest",
+    "darco",
+    "launch",
+    "highway_2",
+];
+
+/// Year each invention becomes available.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Inventions {
+    pub gas_power: i32,
+    pub nuclear_power: i32,
+    pub solar_power: i32,
+    pub wind_power: i32,
+    pub microwave_power: i32,
+    pub fusion_power: i32,
+    pub airport: i32,
+    pub highways: i32,
+    pub buses: i32,
+    pub subways: i32,
+    pub water_treatment: i32,
+    pub desalinisation: i32,
+    pub plymouth: i32,
+    pub forest: i32,
+    pub darco: i32,
+    pub launch: i32,
+    pub highway_2: i32,
+}
+
+impl Inventions {
+    fn from_years(years: &[i32]) -> Self {
+        Self {
+            gas_power: years[0],
+            nuclear_power: years[1],
+            solar_power: years[2],
+            wind_power: years[3],
+            microwave_power: years[4],
+            fusion_power: years[5],
+            airport: years[6],
+            highways: years[7],
+            buses: years[8],
+            subways: years[9],
+            water_treatment: years[10],
+            desalinisation: years[11],
+            plymouth: years[12],
+            forest: years[13],
+            darco: years[14],
+            launch: years[15],
+            highway_2: years[16],
+        }
+    }
+
+    /// Years in the order they are stored in MISC.
+    pub fn years(&self) -> [i32; 17] {
+        [
+            self.gas_power,
+            self.nuclear_power,
+            self.solar_power,
+            self.wind_power,
+            self.microwave_power,
+            self.fusion_power,
+            self.airport,
+            self.highways,
+            self.buses,
+            self.subways,
+            self.water_treatment,
+            self.desalinisation,
+            self.plymouth,
+            self.forest,
+            self.darco,
+            self.launch,
+            self.highway_2,
+        ]
+    }
+}
+
+const ZONE_POP_OFFSET: usize = 0x05F0;
+const VALVE_OFFSET: usize = 0x0718;
+const INVENTIONS_OFFSET: usize = 0x0738;
+const PAPER_LIST_OFFSET: usize = 0x0E50;
+const NEWS_LIST_OFFSET: usize = 0x0EC8;
+const MILITARY_COUNT_OFFSET: usize = 0x0FA8;
+const EXTRA_OFFSET: usize = 0x1050;
+const MISC_LEN: usize = 4800;
+
+/// Scalar values and lists from MISC.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct Misc {
+    /// Nominally the same in every city.
+    pub first_entry: i32,
+    pub game_mode: GameMode,
+    pub compass: Compass,
+    /// Year the simulation started in.
+    pub base_year: i32,
+    /// Days since January 1st of the base year.
+    pub sim_cycle: i32,
+    pub total_funds: i32,
+    pub total_bonds: i32,
+    pub game_level: GameLevel,
+    pub city_status: CityStatus,
+    pub city_value: i32,
+    pub land_value: i32,
+    pub crime_count: i32,
+    pub traffic_count: i32,
+    pub pollution: i32,
+    pub city_fame: i32,
+    pub advertising: i32,
+    pub garbage: i32,
+    pub worker_percent: i32,
+    pub worker_health: i32,
+    pub worker_educate: i32,
+    pub national_pop: i32,
+    pub national_value: i32,
+    pub national_tax: i32,
+    pub national_trend: i32,
+    pub heat: i32,
+    pub wind: i32,
+    pub humid: i32,
+    pub weather_trend: i32,
+    pub new_disaster: i32,
+    pub old_res_pop: i32,
+    pub rewards: i32,
+    /// Population per zone type, 8 entries.
+    pub zone_population: Vec<i32>,
+    /// Named "Valve?" in the game, 8 entries.
+    pub valves: Vec<i32>,
+    pub inventions: Inventions,
+    pub year_end: i32,
+    pub global_sea_level: i32,
+    pub ter_coast: i32,
+    pub ter_river: i32,
+    pub military: i32,
+    /// Raw newspaper list, 30 entries.
+    pub paper_list: Vec<i32>,
+    /// Raw news list, 54 entries.
+    pub news_list: Vec<i32>,
+    pub unemployed: i32,
+    /// 16 entries.
+    pub military_count: Vec<i32>,
+    pub subway_count: i32,
+    pub game_speed: GameSpeed,
+    /// Settings from the options menu are non-zero when on. They are kept as stored, so values other than 0 and 1
+    /// are written back unchanged.
+    pub auto_budget: i32,
+    pub auto_go: i32,
+    pub sound_on: i32,
+    pub music_on: i32,
+    pub no_disasters: i32,
+    pub paper_deliver: i32,
+    pub paper_extra: i32,
+    pub paper_choice: i32,
+    pub unknown128: i32,
+    pub zoom: Zoom,
+    pub city_center_x: i32,
+    pub city_center_y: i32,
+    pub global_arco_pop: i32,
+    pub connect_tiles: i32,
+    pub teams_active: i32,
+    pub total_pop: i32,
+    pub industry_bonus: i32,
+    pub pollute_bonus: i32,
+    pub old_arrest: i32,
+    pub police_bonus: i32,
+    pub disaster_object: i32,
+    pub current_disaster: i32,
+    pub go_disaster: i32,
+    pub sewer_bonus: i32,
+    /// Everything from 0x1050 up to the end of MISC.
+    pub extra: Vec<i32>,
+}
+
+impl Misc {
+    /**
+     * Parses the scalar values and lists from MISC.
+     * Args:
+     *      misc_data (bytes): MISC segment of the raw data from the .sc2 file.
+     */
+    pub fn from_misc_data(misc_data: &[u8]) -> Self {
+        let int = |offset: usize| {
+            parse_int32(
+                misc_data[offset..(offset + 4)]
+                    .try_into()
+                    .expect("should be 4 bytes"),
+            )
+        };
+        let list = |offset: usize, num_items: usize| -> Vec<i32> {
+            (0..num_items).map(|idx| int(offset + idx * 4)).collect()
+        };
+
+        Self {
+            first_entry: int(0x0000),
+            game_mode: GameMode::from_i32(int(0x0004)),
+            compass: Compass::from_i32(int(0x0008)),
+            base_year: int(0x000C),
+            sim_cycle: int(0x0010),
+            total_funds: int(0x0014),
+            total_bonds: int(0x0018),
+            game_level: GameLevel::from_i32(int(0x001C)),
+            city_status: CityStatus::from_i32(int(0x0020)),
+            city_value: int(0x0024),
+            land_value: int(0x0028),
+            crime_count: int(0x002C),
+            traffic_count: int(0x0030),
+            pollution: int(0x0034),
+            city_fame: int(0x0038),
+            advertising: int(0x003C),
+            garbage: int(0x0040),
+            worker_percent: int(0x0044),
+            worker_health: int(0x0048),
+            worker_educate: int(0x004C),
+            national_pop: int(0x0050),
+            national_value: int(0x0054),
+            national_tax: int(0x0058),
+            national_trend: int(0x005C),
+            heat: int(0x0060),
+            wind: int(0x0064),
+            humid: int(0x0068),
+            weather_trend: int(0x006C),
+            new_disaster: int(0x0070),
+            old_res_pop: int(0x0074),
+            rewards: int(0x0078),
+            zone_population: list(ZONE_POP_OFFSET, 8),
+            valves: list(VALVE_OFFSET, 8),
+            inventions: Inventions::from_years(&list(INVENTIONS_OFFSET, 17)),
+            year_end: int(0x0E3C),
+            global_sea_level: int(0x0E40),
+            ter_coast: int(0x0E44),
+            ter_river: int(0x0E48),
+            military: int(0x0E4C),
+            paper_list: list(PAPER_LIST_OFFSET, 6 * 5),
+            news_list: list(NEWS_LIST_OFFSET, 9 * 6),
+            unemployed: int(0x0FA4),
+            military_count: list(MILITARY_COUNT_OFFSET, 16),
+            subway_count: int(0x0FE8),
+            game_speed: GameSpeed::from_i32(int(0x0FEC)),
+            auto_budget: int(0x0FF0),
+            auto_go: int(0x0FF4),
+            sound_on: int(0x0FF8),
+            music_on: int(0x0FFC),
+            no_disasters: int(0x1000),
+            paper_deliver: int(0x1004),
+            paper_extra: int(0x1008),
+            paper_choice: int(0x100C),
+            unknown128: int(0x1010),
+            zoom: Zoom::from_i32(int(0x1014)),
+            city_center_x: int(0x1018),
+            city_center_y: int(0x101C),
+            global_arco_pop: int(0x1020),
+            connect_tiles: int(0x1024),
+            teams_active: int(0x1028),
+            total_pop: int(0x102C),
+            industry_bonus: int(0x1030),
+            pollute_bonus: int(0x1034),
+            old_arrest: int(0x1038),
+            police_bonus: int(0x103C),
+            disaster_object: int(0x1040),
+            current_disaster: int(0x1044),
+            go_disaster: int(0x1048),
+            sewer_bonus: int(0x104C),
+            extra: list(EXTRA_OFFSET, (MISC_LEN - EXTRA_OFFSET) / 4),
+        }
+    }
+
+    /**
+     * Writes the values back into MISC, the inverse of from_misc_data(). Lists that are too short are padded with 0.
+     * Args:
+     *      misc_data (bytes): MISC segment to write into.
+     */
+    pub fn write_misc_data(&self, misc_data: &mut [u8]) {
+        let mut list = |offset: usize, num_items: usize, values: &[i32]| {
+            for idx in 0..num_items {
+                let value = values.get(idx).copied().unwrap_or_default();
+
+                write_int32(misc_data, offset + idx * 4, value);
+            }
+        };
+
+        list(ZONE_POP_OFFSET, 8, &self.zone_population);
+        list(VALVE_OFFSET, 8, &self.valves);
+        list(INVENTIONS_OFFSET, 17, &self.inventions.years());
+        list(PAPER_LIST_OFFSET, 6 * 5, &self.paper_list);
+        list(NEWS_LIST_OFFSET, 9 * 6, &self.news_list);
+        list(MILITARY_COUNT_OFFSET, 16, &self.military_count);
+        list(EXTRA_OFFSET, (MISC_LEN - EXTRA_OFFSET) / 4, &self.extra);
+
+        let values = [
+            (0x0000, self.first_entry),
+            (0x0004, self.game_mode.to_i32()),
+            (0x0008, self.compass.to_i32()),
+            (0x000C, self.base_year),
+            (0x0010, self.sim_cycle),
+            (0x0014, self.total_funds),
+            (0x0018, self.total_bonds),
+            (0x001C, self.game_level.to_i32()),
+            (0x0020, self.city_status.to_i32()),
+            (0x0024, self.city_value),
+            (0x0028, self.land_value),
+            (0x002C, self.crime_count),
+            (0x0030, self.traffic_count),
+            (0x0034, self.pollution),
+            (0x0038, self.city_fame),
+            (0x003C, self.advertising),
+            (0x0040, self.garbage),
+            (0x0044, self.worker_percent),
+            (0x0048, self.worker_health),
+            (0x004C, self.worker_educate),
+            (0x0050, self.national_pop),
+            (0x0054, self.national_value),
+            (0x0058, self.national_tax),
+            (0x005C, self.national_trend),
+            (0x0060, self.heat),
+            (0x0064, self.wind),
+            (0x0068, self.humid),
+            (0x006C, self.weather_trend),
+            (0x0070, self.new_disaster),
+            (0x0074, self.old_res_pop),
+            (0x0078, self.rewards),
+            (0x0E3C, self.year_end),
+            (0x0E40, self.global_sea_level),
+            (0x0E44, self.ter_coast),
+            (0x0E48, self.ter_river),
+            (0x0E4C, self.military),
+            (0x0FA4, self.unemployed),
+            (0x0FE8, self.subway_count),
+            (0x0FEC, self.game_speed.to_i32()),
+            (0x0FF0, self.auto_budget),
+            (0x0FF4, self.auto_go),
+            (0x0FF8, self.sound_on),
+            (0x0FFC, self.music_on),
+            (0x1000, self.no_disasters),
+            (0x1004, self.paper_deliver),
+            (0x1008, self.paper_extra),
+            (0x100C, self.paper_choice),
+            (0x1010, self.unknown128),
+            (0x1014, self.zoom.to_i32()),
+            (0x1018, self.city_center_x),
+            (0x101C, self.city_center_y),
+            (0x1020, self.global_arco_pop),
+            (0x1024, self.connect_tiles),
+            (0x1028, self.teams_active),
+            (0x102C, self.total_pop),
+            (0x1030, self.industry_bonus),
+            (0x1034, self.pollute_bonus),
+            (0x1038, self.old_arrest),
+            (0x103C, self.police_bonus),
+            (0x1040, self.disaster_object),
+            (0x1044, self.current_disaster),
+            (0x1048, self.go_disaster),
+            (0x104C, self.sewer_bonus),
+        ];
+
+        for (offset, value) in values {
+            write_int32(misc_data, offset, value);
+        }
+    }
+
+    /// Current in-game date.
+    pub fn date(&self) -> Date {
+        Date::from_sim_cycle(self.base_year, self.sim_cycle)
+    }
+
+    /// Time since January 1st of the base year.
+    pub fn age(&self) -> CityAge {
+        CityAge::from_sim_cycle(self.sim_cycle)
+    }
+}
@@ -1,14 +1,57 @@
 mod bit_flags;
+mod bonds;
 mod budget;
+mod budget_summary;
 mod building;
 mod buildings;
+mod calendar;
 mod city;
+mod cord_hash_map;
 mod graph;
+mod grid;
 mod indexed_write;
+mod invention;
 mod minimap;
+mod misc;
+mod neighbour;
+mod newspaper;
+mod ordinances;
+mod parse_error;
 mod sc2_iff_parse;
 mod sc_util;
+mod terrain;
+#[cfg(test)]
+mod test_rng;
 mod thing;
 mod tile;
+mod tile_layers;
+mod underground;
+mod zone;
 
-pub use city::City;
+pub use bit_flags::BitFlags;
+pub use bonds::{Bond, BondSummary, BOND_AMOUNT};
+pub use budget::Budget;
+pub use budget_summary::{BudgetCategory, BudgetKind, BudgetSummary};
+pub use building::Building;
+pub use calendar::{CityAge, Date, DAYS_PER_MONTH, MONTHS_PER_YEAR};
+pub use city::{City, FORMAT_VERSION};
+pub use graph::Graph;
+pub use grid::Grid;
+pub use invention::{BuildableBuilding, Invention};
+pub use minimap::Minimap;
+pub use misc::{
+    CityStatus, Compass, GameLevel, GameMode, GameSpeed, Inventions, Misc, Zoom, INVENTION_NAMES,
+};
+pub use neighbour::{Direction, NeighbourCity, Neighbours};
+pub use newspaper::{NewsEntry, Newspapers, PaperEntry};
+pub use ordinances::Ordinances;
+pub use parse_error::ParseError;
+pub use sc2_iff_parse::{
+    chunk_input_serial, compress_rle, sc2_uncompress_input, uncompress_rle, ChunkList, RawChunk,
+};
+pub use terrain::{Slope, Terrain, WaterKind};
+pub use thing::Thing;
+pub use tile::Tile;
+pub use tile_layers::{BitFlagLayers, TileLayers};
+pub use underground::{NetworkPiece, Side, Underground, UndergroundNetwork};
+pub use zone::{Corners, Zone};
@@ -0,0 +1,139 @@
+// The four neighbouring cities from MISC, stored as name index, population, value and fame for each direction.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::sc_util::{parse_int32, write_int32};
+
+/// Side of the map a neighbour is on, in the order they are stored in MISC.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Direction {
+    #[default]
+    North,
+    East,
+    South,
+    West,
+}
+
+/// A neighbouring city.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct NeighbourCity {
+    pub direction: Direction,
+    /// Index into the game's table of neighbour city names.
+    pub name_index: i32,
+    /// Name looked up with Neighbours::resolve_names(). The game's name table isn't part of the save file, so this is
+    /// missing unless a table is supplied, e.g. with --neighbour-names.
+    pub name: Option<String>,
+    pub population: i32,
+    pub value: i32,
+    pub fame: i32,
+}
+
+impl NeighbourCity {
+    fn from_misc_data(direction: Direction, misc_data: &[u8], offset: usize) -> Self {
+        let int = |index: usize| {
+            let start = offset + index * 4;
+
+            parse_int32(
+                misc_data[start..(start + 4)]
+                    .try_into()
+                    .expect("should be 4 bytes"),
+            )
+        };
+
+        Self {
+            direction,
+            name_index: int(0),
+            name: None,
+            population: int(1),
+            value: int(2),
+            fame: int(3),
+        }
+    }
+
+    fn write_misc_data(&self, misc_data: &mut [u8], offset: usize) {
+        let values = [self.name_index, self.population, self.value, self.fame];
+
+        for (index, value) in values.into_iter().enumerate() {
+            write_int32(misc_data, offset + index * 4, value);
+        }
+    }
+}
+
+/// One neighbouring city per side of the map.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Neighbours {
+    pub north: NeighbourCity,
+    pub east: NeighbourCity,
+    pub south: NeighbourCity,
+    pub west: NeighbourCity,
+}
+
+/// Size of a single neighbour in MISC, 4 values of 4 bytes.
+const NEIGHBOUR_LEN: usize = 16;
+
+impl Neighbours {
+    /**
+     * Parses the neighbours from MISC.
+     * Args:
+     *      misc_data (bytes): MISC segment of the raw data from the .sc2 file.
+     *      offset (int): Offset of the first neighbour.
+     */
+    pub fn from_misc_data(misc_data: &[u8], offset: usize) -> Self {
+        let neighbour = |index: usize, direction| {
+            NeighbourCity::from_misc_data(direction, misc_data, offset + index * NEIGHBOUR_LEN)
+        };
+
+        Self {
+            north: neighbour(0, Direction::North),
+            east: neighbour(1, Direction::East),
+            south: neighbour(2, Direction::South),
+            west: neighbour(3, Direction::West),
+        }
+    }
+
+    /**
+     * Writes the neighbours back into MISC, the inverse of from_misc_data().
+     * Args:
+     *      misc_data (bytes): MISC segment to write into.
+     *      offset (int): Offset of the first neighbour.
+     */
+    pub fn write_misc_data(&self, misc_data: &mut [u8], offset: usize) {
+        for (index, neighbour) in self.iter().enumerate() {
+            neighbour.write_misc_data(misc_data, offset + index * NEIGHBOUR_LEN);
+        }
+    }
+
+    /// The neighbours in the order they are stored in MISC.
+    pub fn iter(&self) -> impl Iterator<Item = &NeighbourCity> {
+        [&self.north, &self.east, &self.south, &self.west].into_iter()
+    }
+
+    pub fn get(&self, direction: Direction) -> &NeighbourCity {
+        match direction {
+            Direction::North => &self.north,
+            Direction::East => &self.east,
+            Direction::South => &self.south,
+            Direction::West => &self.west,
+        }
+    }
+
+    /**
+     * Looks up the names of the neighbours. The name table is part of the game's resources and isn't shipped here.
+     * Args:
+     *      names: the game's neighbour city names, indexed by name_index.
+     */
+    pub fn resolve_names(&mut self, names: &[impl AsRef<str>]) {
+        for neighbour in [
+            &mut self.north,
+            &mut self.east,
+            &mut self.south,
+            &mut self.west,
+        ] {
+            neighbour.name = usize::try_from(neighbour.name_index)
+                .ok()
+                .and_then(|index| names.get(index))
+                .map(|name| name.as_ref().to_string());
+        }
+    }
+}
@@ -0,0 +1,95 @@
+// Newspaper history from the Paper List and News List in MISC, along with the newspaper settings. Story and
+// headline ids index the game's own text tables, which aren't part of the save file, so they are kept as raw ids.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::misc::Misc;
+
+/// Values per entry of the Paper List, the paper followed by its stories.
+const PAPER_ENTRY_LEN: usize = 5;
+/// Values per entry of the News List, the story and headline followed by the values filled into the headline.
+const NEWS_ENTRY_LEN: usize = 6;
+
+/// A newspaper edition from the Paper List.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PaperEntry {
+    /// Position in the Paper List, 0 to 5.
+    pub index: usize,
+    /// Which of the city's papers the edition belongs to.
+    pub paper: i32,
+    /// Ids of the stories printed in the edition, opaque without the game's story table.
+    pub story_ids: Vec<i32>,
+}
+
+/// A story from the News List.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct NewsEntry {
+    /// Position in the News List, 0 to 8.
+    pub index: usize,
+    /// Id of the story in the game's story table, kept raw.
+    pub story_id: i32,
+    /// Id of the headline template the story is printed with, kept raw.
+    pub headline_id: i32,
+    /// Values filled into the headline, like amounts or names.
+    pub values: Vec<i32>,
+}
+
+/// Newspaper settings and the recent newspapers of a city.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Newspapers {
+    /// PaperDeliver, whether the papers are delivered regularly.
+    pub subscription: bool,
+    /// PaperExtra, whether extra editions are printed for disasters.
+    pub extra: bool,
+    /// PaperChoice, the paper picked in the newspaper menu.
+    pub paper_choice: i32,
+    /// Non-empty entries of the Paper List.
+    pub papers: Vec<PaperEntry>,
+    /// Non-empty entries of the News List.
+    pub news: Vec<NewsEntry>,
+}
+
+impl Newspapers {
+    /**
+     * Groups the flat Paper List and News List into entries.
+     * Args:
+     *      misc: values from MISC.
+     */
+    pub fn from_misc(misc: &Misc) -> Self {
+        let entries = |list: &[i32], len: usize| -> Vec<(usize, Vec<i32>)> {
+            list.chunks_exact(len)
+                .map(<[i32]>::to_vec)
+                .enumerate()
+                .filter(|(_, entry)| entry.iter().any(|value| *value != 0))
+                .collect()
+        };
+
+        let papers = entries(&misc.paper_list, PAPER_ENTRY_LEN)
+            .into_iter()
+            .map(|(index, entry)| PaperEntry {
+                index,
+                paper: entry[0],
+                story_ids: entry[1..].to_vec(),
+            })
+            .collect();
+
+        let news = entries(&misc.news_list, NEWS_ENTRY_LEN)
+            .into_iter()
+            .map(|(index, entry)| NewsEntry {
+                index,
+                story_id: entry[0],
+                headline_id: entry[1],
+                values: entry[2..].to_vec(),
+            })
+            .collect();
+
+        Self {
+            subscription: misc.paper_deliver != 0,
+            extra: misc.paper_extra != 0,
+            paper_choice: misc.paper_choice,
+            papers,
+            news,
+        }
+    }
+}
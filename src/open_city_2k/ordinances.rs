@@ -0,0 +1,146 @@
+// Ordinances stored as a bit field in the MISC word at 0x0FA0, starting with sales tax in the lowest bit.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Enacted ordinances of a city.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Ordinances {
+    // Finance
+    pub sales_tax: bool,
+    pub income_tax: bool,
+    pub legalized_gambling: bool,
+    pub parking_fines: bool,
+    // Education
+    pub pro_reading_campaign: bool,
+    pub anti_drug_campaign: bool,
+    pub cpr_training: bool,
+    pub neighborhood_watch: bool,
+    // Other
+    pub energy_conservation: bool,
+    pub nuclear_free_zone: bool,
+    pub homeless_shelters: bool,
+    pub pollution_controls: bool,
+    // Safety & Health
+    pub volunteer_fire_dept: bool,
+    pub public_smoking_ban: bool,
+    pub free_clinics: bool,
+    pub junior_sports: bool,
+    // Promotional
+    pub tourist_advertising: bool,
+    pub business_advertising: bool,
+    pub city_beautification: bool,
+    pub annual_carnival: bool,
+    /// Bits 20 - 31, which the game doesn't use. Kept so the word can be written back unchanged.
+    pub unused_bits: u32,
+}
+
+const ORDINANCE_COUNT: u32 = 20;
+const UNUSED_MASK: u32 = !((1 << ORDINANCE_COUNT) - 1);
+
+impl Ordinances {
+    /**
+     * Decodes the ordinance word.
+     * Args:
+     *      bits (int): MISC value at 0x0FA0.
+     */
+    pub fn from_bits(bits: u32) -> Self {
+        let bit = |index: u32| bits & (1 << index) != 0;
+
+        Self {
+            sales_tax: bit(0),
+            income_tax: bit(1),
+            legalized_gambling: bit(2),
+            parking_fines: bit(3),
+            pro_reading_campaign: bit(4),
+            anti_drug_campaign: bit(5),
+            cpr_training: bit(6),
+            neighborhood_watch: bit(7),
+            energy_conservation: bit(8),
+            nuclear_free_zone: bit(9),
+            homeless_shelters: bit(10),
+            pollution_controls: bit(11),
+            volunteer_fire_dept: bit(12),
+            public_smoking_ban: bit(13),
+            free_clinics: bit(14),
+            junior_sports: bit(15),
+            tourist_advertising: bit(16),
+            business_advertising: bit(17),
+            city_beautification: bit(18),
+            annual_carnival: bit(19),
+            unused_bits: bits & UNUSED_MASK,
+        }
+    }
+
+    /// Encodes the ordinances back into the MISC word, the inverse of from_bits().
+    pub fn to_bits(&self) -> u32 {
+        self.flags()
+            .iter()
+            .enumerate()
+            .fold(self.unused_bits & UNUSED_MASK, |bits, (index, flag)| {
+                bits | (*flag as u32) << index
+            })
+    }
+
+    /**
+     * Lists the ordinances that are in effect.
+     * Returns:
+     *      Names of the enacted ordinances, as shown in the game.
+     */
+    pub fn enacted(&self) -> Vec<&'static str> {
+        ORDINANCE_NAMES
+            .iter()
+            .zip(self.flags())
+            .filter(|(_, enacted)| *enacted)
+            .map(|(name, _)| *name)
+            .collect()
+    }
+
+    fn flags(&self) -> [bool; ORDINANCE_COUNT as usize] {
+        [
+            self.sales_tax,
+            self.income_tax,
+            self.legalized_gambling,
+            self.parking_fines,
+            self.pro_reading_campaign,
+            self.anti_drug_campaign,
+            self.cpr_training,
+            self.neighborhood_watch,
+            self.energy_conservation,
+            self.nuclear_free_zone,
+            self.homeless_shelters,
+            self.pollution_controls,
+            self.volunteer_fire_dept,
+            self.public_smoking_ban,
+            self.free_clinics,
+            self.junior_sports,
+            self.tourist_advertising,
+            self.business_advertising,
+            self.city_beautification,
+            self.annual_carnival,
+        ]
+    }
+}
+
+const ORDINANCE_NAMES: [&str; ORDINANCE_COUNT as usize] = [
+    "Sales Tax",
+    "Income Tax",
+    "Legalized Gambling",
+    "Parking Fines",
+    "Pro-Reading Campaign",
+    "Anti-Drug Campaign",
+    "CPR Training",
+    "Neighborhood Watch",
+    "Energy Conservation",
+    "Nuclear Free Zone",
+    "Homeless Shelters",
+    "Pollution Controls",
+    "Volunteer Fire Dept.",
+    "Public Smoking Ban",
+    "Free Clinics",
+    "Junior Sports",
+    "Tourist Advertising",
+    "Business Advertising",
+    "City Beautification",
+    "Annual Carnival",
+];
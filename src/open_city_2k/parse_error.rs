@@ -0,0 +1,138 @@
+use std::fmt::Display;
+use std::io;
+
+/// Errors that can occur while parsing a .sc2 or .mif file.
+/// Offsets are byte offsets into the file for container errors and into the uncompressed chunk otherwise.
+#[derive(Debug)]
+pub enum ParseError {
+    /// Reading the input failed.
+    Io(io::Error),
+    /// The data ends before a header or chunk that's being read.
+    Truncated {
+        chunk_id: String,
+        offset: usize,
+        needed: usize,
+        available: usize,
+    },
+    /// The size the file reports in its header doesn't match the actual size.
+    BadSizeHeader { reported: usize, actual: usize },
+    /// A chunk doesn't have the length the game always writes for it.
+    UnexpectedChunkLength {
+        chunk_id: String,
+        expected: usize,
+        actual: usize,
+    },
+    /// XBLD contains an id that's not in the building table.
+    UnknownBuildingId { building_id: u8, offset: usize },
+    /// A value is outside of the range the game uses for it.
+    InvalidValue {
+        chunk_id: String,
+        offset: usize,
+        value: i64,
+    },
+    /// The file is a Simcity Classic city, which isn't supported.
+    SimCityClassic,
+    /// The file is not an IFF file of the expected type, claiming the given header instead.
+    NotIff { expected: String, header: String },
+    /// The IFF file doesn't contain the expected type of data.
+    WrongFileType { expected: String, file_type: String },
+    /// The requested input type is neither "sc2" nor "mif".
+    UnknownInputType(String),
+}
+
+impl ParseError {
+    /**
+     * Checks that a slice contains enough bytes for the data that's about to be read.
+     * Args:
+     *      chunk_id (str): id of the chunk or header being read.
+     *      data (bytes): data that's being read.
+     *      offset (int): offset into data where reading starts.
+     *      needed (int): number of bytes to read.
+     * Returns:
+     *      The requested bytes, or a Truncated error if data is too short.
+     */
+    pub fn check_range<'a>(
+        chunk_id: &str,
+        data: &'a [u8],
+        offset: usize,
+        needed: usize,
+    ) -> Result<&'a [u8], Self> {
+        offset
+            .checked_add(needed)
+            .and_then(|end| data.get(offset..end))
+            .ok_or_else(|| Self::Truncated {
+                chunk_id: chunk_id.to_owned(),
+                offset,
+                needed,
+                available: data.len().saturating_sub(offset),
+            })
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to read input: {err}"),
+            Self::Truncated {
+                chunk_id,
+                offset,
+                needed,
+                available,
+            } => write!(
+                f,
+                "{chunk_id} is truncated at offset {offset:#x}: needed {needed}B, but only {available}B are left."
+            ),
+            Self::BadSizeHeader { reported, actual } => write!(
+                f,
+                "File reports being: {reported}B, but is actually {actual}B long."
+            ),
+            Self::UnexpectedChunkLength {
+                chunk_id,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{chunk_id} should be {expected}B long, but is {actual}B long."
+            ),
+            Self::UnknownBuildingId {
+                building_id,
+                offset,
+            } => write!(
+                f,
+                "XBLD contains invalid building id {building_id:#04x} at offset {offset:#x}."
+            ),
+            Self::InvalidValue {
+                chunk_id,
+                offset,
+                value,
+            } => write!(
+                f,
+                "{chunk_id} contains invalid value {value} at offset {offset:#x}."
+            ),
+            Self::SimCityClassic => write!(f, "Simcity Classic city files are not supported."),
+            Self::NotIff { expected, header } => {
+                write!(f, "Not a {expected} type IFF file, claiming: {header}")
+            }
+            Self::WrongFileType {
+                expected,
+                file_type,
+            } => write!(f, "File type is not {expected}, claiming: {file_type}"),
+            Self::UnknownInputType(input_type) => write!(f, "unknown input type: {input_type}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ParseError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
@@ -1,6 +1,7 @@
+use super::parse_error::ParseError;
 use super::sc_util;
-use anyhow::{anyhow, Result};
-use std::collections::HashMap;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 #[allow(dead_code)]
 struct Sc2FileHeader {
@@ -39,15 +40,31 @@ pub struct ChunkList {
     xthg: Vec<u8>,
     xgrp: Vec<u8>,
 
-    unknown: HashMap<String, Vec<u8>>,
+    /// Chunks the parser doesn't know, in the order they were read and never RLE decoded.
+    unknown: Vec<(String, Vec<u8>)>,
 }
 
+/// A chunk the city parser doesn't decode, kept as it was read so it can be written back unchanged.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct RawChunk {
+    /// 4 letter id of the chunk.
+    pub id: String,
+    pub data: Vec<u8>,
+}
+
+/// Order in which the game writes the chunks of a .sc2 file.
+const SC2_CHUNK_ORDER: [&str; 21] = [
+    "CNAM", "MISC", "ALTM", "XTER", "XBLD", "XZON", "XUND", "XTXT", "XLAB", "XMIC", "XTHG", "XBIT",
+    "XTRF", "XPLT", "XVAL", "XCRM", "XPLC", "XFIR", "XPOP", "XROG", "XGRP",
+];
+
 impl ChunkList {
-    fn set(&mut self, id: &str, value: &[u8]) {
+    pub fn set(&mut self, id: &str, value: &[u8]) {
         match id {
             "TEXT" => self.text = vec![value.to_vec()],
             "CNAM" => self.cnam = value.into(),
             "ALTM" => self.altm = value.into(),
+            "SCEN" => self.scen = value.into(),
             "PICT" => self.pict = value.into(),
             "TILE" => self.tile = value.into(),
 
@@ -71,10 +88,102 @@ impl ChunkList {
             "XTHG" => self.xthg = value.into(),
             "XGRP" => self.xgrp = value.into(),
 
+            _ => match self.unknown.iter_mut().find(|(key, _)| key == id) {
+                Some((_, unknown)) => *unknown = value.to_vec(),
+                None => self.unknown.push((id.into(), value.to_vec())),
+            },
+        };
+    }
+
+    fn get(&self, id: &str) -> Option<&[u8]> {
+        let value = match id {
+            "CNAM" => &self.cnam,
+            "ALTM" => &self.altm,
+            "SCEN" => &self.scen,
+            "PICT" => &self.pict,
+            "TILE" => &self.tile,
+
+            "MISC" => &self.misc,
+            "XTER" => &self.xter,
+            "XBLD" => &self.xbld,
+            "XTRF" => &self.xtrf,
+            "XPLT" => &self.xplt,
+            "XVAL" => &self.xval,
+            "XCRM" => &self.xcrm,
+            "XZON" => &self.xzon,
+            "XUND" => &self.xund,
+            "XTXT" => &self.xtxt,
+            "XBIT" => &self.xbit,
+            "XPLC" => &self.xplc,
+            "XFIR" => &self.xfir,
+            "XPOP" => &self.xpop,
+            "XROG" => &self.xrog,
+            "XLAB" => &self.xlab,
+            "XMIC" => &self.xmic,
+            "XTHG" => &self.xthg,
+            "XGRP" => &self.xgrp,
+
             _ => {
-                self.unknown.insert(id.into(), value.to_vec());
+                return self
+                    .unknown
+                    .iter()
+                    .find(|(key, _)| key == id)
+                    .map(|(_, unknown)| unknown.as_slice())
             }
         };
+
+        Some(value)
+    }
+
+    /**
+     * Lists every non-empty chunk, in the order the game writes them followed by scenario and unknown chunks.
+     * Returns:
+     *      Pairs of chunk id and chunk data.
+     */
+    pub fn chunks(&self) -> impl Iterator<Item = (&str, &[u8])> {
+        let known_chunks = SC2_CHUNK_ORDER
+            .iter()
+            .chain(["SCEN", "PICT"].iter())
+            .filter_map(|id| Some((*id, self.get(id)?)));
+        let text_chunks = self.text.iter().map(|text| ("TEXT", text.as_slice()));
+        let unknown_chunks = self
+            .unknown
+            .iter()
+            .map(|(id, value)| (id.as_str(), value.as_slice()));
+
+        known_chunks
+            .chain(text_chunks)
+            .chain(unknown_chunks)
+            .filter(|(_, value)| !value.is_empty())
+    }
+
+    /**
+     * Collects the chunks the city parser doesn't decode: TEXT, SCEN, PICT and chunks it doesn't know.
+     * Returns:
+     *      The chunks as they were read.
+     */
+    pub fn raw_chunks(&self) -> Vec<RawChunk> {
+        self.chunks()
+            .filter(|(id, _)| {
+                ["TEXT", "SCEN", "PICT"].contains(id) || !SC2_CHUNK_ORDER.contains(id)
+            })
+            .map(|(id, data)| RawChunk {
+                id: id.to_owned(),
+                data: data.to_vec(),
+            })
+            .collect()
+    }
+
+    /**
+     * Adds a chunk that was kept by raw_chunks(), the inverse of it.
+     * Args:
+     *      chunk (RawChunk): chunk to add, TEXT chunks are appended to the existing ones.
+     */
+    pub fn add_raw_chunk(&mut self, chunk: &RawChunk) {
+        match chunk.id.as_str() {
+            "TEXT" => self.text.push(chunk.data.clone()),
+            id => self.set(id, &chunk.data),
+        }
     }
 
     fn iter_compressed(&self) -> CompressedIterator {
@@ -195,8 +304,6 @@ impl<'a> Iterator for CompressedIterator<'a> {
     type Item = (String, Vec<u8>);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let offset = 19;
-
         let next = match self.cursor {
             0 => Some((String::from("MISC"), &self.list.misc)),
             1 => Some((String::from("XTER"), &self.list.xter)),
@@ -217,13 +324,6 @@ impl<'a> Iterator for CompressedIterator<'a> {
             16 => Some((String::from("XMIC"), &self.list.xmic)),
             17 => Some((String::from("XTHG"), &self.list.xthg)),
             18 => Some((String::from("XGRP"), &self.list.xgrp)),
-
-            i if (i - offset) < self.list.unknown.len() => self
-                .list
-                .unknown
-                .iter()
-                .nth(i - offset)
-                .map(|(key, value)| (key.to_owned(), value)),
             _ => None,
         };
 
@@ -241,8 +341,10 @@ impl<'a> Iterator for CompressedIterator<'a> {
  *      A string of the name, with garbage removed.
  */
 pub fn clean_city_name(dirty_name: &[u8]) -> String {
-    dirty_name[1..32]
+    dirty_name
         .iter()
+        .skip(1)
+        .take(31)
         .take_while(|x| **x != 0x00)
         .map(|x| char::from(x.to_owned()))
         .collect()
@@ -258,9 +360,9 @@ pub fn clean_city_name(dirty_name: &[u8]) -> String {
  * Returns:
  *      A dictionary of {chunk id: chunk data} form, one entry per chunk.
  * Raises:
- *      SC2Parse: re-raised errors from check_file()
+ *      ParseError: re-raised errors from check_file() or a chunk that extends past the end of the file.
  */
-pub fn chunk_input_serial(input_file: &[u8], input_type: &str) -> Result<ChunkList> {
+pub fn chunk_input_serial(input_file: &[u8], input_type: &str) -> Result<ChunkList, ParseError> {
     let mut output_dict = ChunkList::default();
     let (header, input_file) = check_file(input_file, input_type)?;
 
@@ -277,7 +379,7 @@ pub fn chunk_input_serial(input_file: &[u8], input_type: &str) -> Result<ChunkLi
 
     while remaining_length > 0 {
         let offset = file_length - remaining_length;
-        let (chunk_id, chunk_size, chunk_data) = get_chunk_from_offset(input_file, offset);
+        let (chunk_id, chunk_size, chunk_data) = get_chunk_from_offset(input_file, offset)?;
 
         if chunk_id == "TEXT" {
             output_dict.text.push(chunk_data.to_vec());
@@ -303,44 +405,41 @@ pub fn chunk_input_serial(input_file: &[u8], input_type: &str) -> Result<ChunkLi
  *      A tuple containing a dictionary and the input.
  *      The dictionary looks like {'type_id': header, 'data_size': reported_size, 'file_type': file_type} where the header is the opening 4 bytes of input as a bytestring, reported_size is an int of the size the file claims to be and file_type is one of b"SC2K" (tileset) of b"SCDH" (city).
  * Raises:
- *      SC2Parse: an error relating to parsing .sc2 files. Could be caused by the file being a SimCity classic city (currently an unsupported format), not a city file at all, or being corrupted.
- *      MIFFParse: an error relating to parsing .mif files. Could be caused by file corruption of not actually being a tileset file.
+ *      ParseError: Could be caused by the file being a SimCity classic city (currently an unsupported format), not a city or tileset file at all, or being corrupted.
  */
-fn check_file<'a>(input_data: &'a [u8], input_type: &str) -> Result<(Sc2FileHeader, &'a [u8])> {
+fn check_file<'a>(
+    input_data: &'a [u8],
+    input_type: &str,
+) -> Result<(Sc2FileHeader, &'a [u8]), ParseError> {
     let mut city_name: Option<Vec<u8>> = None;
     let mut input_data = input_data;
 
     // Check and convert if this is a Mac city file.
     if mac_check(input_data) {
-        let (fixed_input_data, fixed_city_name) = mac_fix(input_data);
+        let (fixed_input_data, fixed_city_name) = mac_fix(input_data)?;
 
         input_data = fixed_input_data;
         city_name = Some(fixed_city_name.to_vec());
     }
 
+    let raw_header = ParseError::check_range("FORM", input_data, 0, 12)?;
+
     // This should be "FORM" for .sc2
-    let header: &[u8; 4] = &input_data[0..4].try_into().expect("should be 4 bytes");
+    let header = &raw_header[0..4];
 
     // The reported size saved in the .sc2, we don't count the first 8 bytes though, so we need to add them back.
-    let reported_size =
-        sc_util::parse_int32(input_data[4..8].try_into().expect("should be 4 bytes")) + 8;
+    let reported_size = sc_util::parse_uint32(
+        raw_header[4..8]
+            .try_into()
+            .expect("header has been checked to be 12 bytes"),
+    ) as usize
+        + 8;
 
     // This should be "SCDH"
-    let file_type = input_data[8..12].try_into().expect("should be 4 bytes");
+    let file_type = &raw_header[8..12];
 
     // Actual size of our input file
-    let actual_size: i32 = input_data
-        .len()
-        .try_into()
-        .expect("actual_size should fit in i32");
-
-    if reported_size != actual_size {
-        return Err(anyhow!(
-            "File reports being: {}B, but is actually {}B long.",
-            reported_size,
-            actual_size
-        ));
-    }
+    let actual_size = input_data.len();
 
     let header_string = String::from_utf8_lossy(header);
     let file_type_string = String::from_utf8_lossy(file_type);
@@ -349,51 +448,59 @@ fn check_file<'a>(input_data: &'a [u8], input_type: &str) -> Result<(Sc2FileHead
     match input_type {
         "sc2" => {
             if header_string != "FORM" {
-                let data_match =
-                    input_data[0x41..0x49] == [0x43, 0x49, 0x54, 0x59, 0x4D, 0x43, 0x52, 0x50];
+                let data_match = input_data.get(0x41..0x49)
+                    == Some(&[0x43, 0x49, 0x54, 0x59, 0x4D, 0x43, 0x52, 0x50]);
                 let header_match = header[0..2] == [0x00, 0x0d];
 
                 // Check and see if this is a Simcity Classic city.
-                let error = if data_match && header_match {
-                    String::from("Simcity Classic city files are not supported.")
-                } else {
-                    format!("Not a FORM type IFF file, claiming: {}", header_string)
-                };
-
-                return Err(anyhow!(error));
+                if data_match && header_match {
+                    return Err(ParseError::SimCityClassic);
+                }
+
+                return Err(ParseError::NotIff {
+                    expected: String::from("FORM"),
+                    header: header_string.into_owned(),
+                });
             }
 
             if file_type_string != "SCDH" {
-                return Err(anyhow!(
-                    "File type is not SCDH, claiming: {}",
-                    file_type_string
-                ));
+                return Err(ParseError::WrongFileType {
+                    expected: String::from("SCDH"),
+                    file_type: file_type_string.into_owned(),
+                });
             }
         }
 
         "mif" => {
             if header_string != "MIFF" {
-                return Err(anyhow!(
-                    "Not a MIFF type IFF file, claiming: {}",
-                    header_string
-                ));
+                return Err(ParseError::NotIff {
+                    expected: String::from("MIFF"),
+                    header: header_string.into_owned(),
+                });
             }
 
             if file_type_string != "SC2K" {
-                return Err(anyhow!(
-                    "File type is not SC2K, claiming: {}",
-                    file_type_string
-                ));
+                return Err(ParseError::WrongFileType {
+                    expected: String::from("SC2K"),
+                    file_type: file_type_string.into_owned(),
+                });
             }
         }
 
-        _ => panic!("unknown input type: {}", input_type),
+        _ => return Err(ParseError::UnknownInputType(input_type.to_owned())),
+    }
+
+    if reported_size != actual_size {
+        return Err(ParseError::BadSizeHeader {
+            reported: reported_size,
+            actual: actual_size,
+        });
     }
 
     Ok((
         Sc2FileHeader {
             type_id: header_string.into_owned(),
-            data_size: reported_size as usize,
+            data_size: reported_size,
             file_type: file_type_string.into_owned(),
             city_name,
         },
@@ -409,22 +516,28 @@ fn check_file<'a>(input_data: &'a [u8], input_type: &str) -> Result<(Sc2FileHead
  *      offset (int): starting offset in input to start parsing at.
  * Returns:
  *      A list containing the id of the chunk (a 4 byte ascii value), an int length of the chunk of finally bytes of the chunk data.
+ * Raises:
+ *      ParseError: the chunk header or data extends past the end of the input.
  */
-fn get_chunk_from_offset(input_data: &[u8], offset: usize) -> (String, usize, &[u8]) {
+fn get_chunk_from_offset(
+    input_data: &[u8],
+    offset: usize,
+) -> Result<(String, usize, &[u8]), ParseError> {
     let location_index = offset;
-    let chunk_id =
-        String::from_utf8_lossy(&input_data[location_index..location_index + 4]).into_owned();
+    let chunk_header = ParseError::check_range("IFF chunk header", input_data, location_index, 8)?;
+    let chunk_id = String::from_utf8_lossy(&chunk_header[0..4]).into_owned();
 
     // Maximum 32b/4B, so 2^32 in length.
     let chunk_size = sc_util::parse_uint32(
-        input_data[(location_index + 4)..(location_index + 8)]
+        chunk_header[4..8]
             .try_into()
-            .expect("should be 4 bytes"),
+            .expect("chunk header has been checked to be 8 bytes"),
     ) as usize;
 
-    let chunk_data = &input_data[(location_index + 8)..(location_index + 8 + chunk_size)];
+    let chunk_data =
+        ParseError::check_range(&chunk_id, input_data, location_index + 8, chunk_size)?;
 
-    (chunk_id, chunk_size, chunk_data)
+    Ok((chunk_id, chunk_size, chunk_data))
 }
 
 /**
@@ -435,10 +548,10 @@ fn get_chunk_from_offset(input_data: &[u8], offset: usize) -> (String, usize, &[
  *      True if this is a Mac formatted file, False if it isn't.
  */
 fn mac_check(input_data: &[u8]) -> bool {
-    let header = &input_data[0..4];
-    let mac_form = &input_data[0x80..0x84];
+    let header = input_data.get(0..4);
+    let mac_form = input_data.get(0x80..0x84);
 
-    String::from_utf8_lossy(header) != "FORM" && String::from_utf8_lossy(mac_form) == "FORM"
+    header != Some(b"FORM") && mac_form == Some(b"FORM")
 }
 
 /**
@@ -448,30 +561,42 @@ fn mac_check(input_data: &[u8]) -> bool {
  *      input_data (bytes): raw city information.
  * Returns:
  *      Bytes comprising a compatible SC2k Win95 city file from the Mac file, and the name of the city from the start of the file.
+ * Raises:
+ *      ParseError: the file is shorter than it reports to be.
  */
-fn mac_fix(input_data: &[u8]) -> (&[u8], &[u8]) {
-    let reported_size = (sc_util::parse_int32(
-        input_data[0x84..0x88]
+fn mac_fix(input_data: &[u8]) -> Result<(&[u8], &[u8]), ParseError> {
+    let raw_size = ParseError::check_range("FORM", input_data, 0x84, 4)?;
+    let reported_size = sc_util::parse_uint32(
+        raw_size
             .try_into()
-            .expect("should be 4 bytes"),
-    ) + 8) as usize;
+            .expect("size has been checked to be 4 bytes"),
+    ) as usize
+        + 8;
 
     let name_len = input_data[1] as usize;
-    let city_name = &input_data[1..2 + name_len];
+    let city_name = ParseError::check_range("Mac header", input_data, 1, 1 + name_len)?;
 
-    (&input_data[0x80..(0x80 + reported_size)], city_name)
+    Ok((
+        ParseError::check_range("FORM", input_data, 0x80, reported_size)?,
+        city_name,
+    ))
 }
 
 /**
  * Uncompresses a compressed .mif or .sc2 file.
- * For a .sc2 file, doesn't uncompress chunks with id of CNAM or ALTM and for .mif, soesn't uncompress TILE chunks.
+ * For a .sc2 file, doesn't uncompress chunks with id of CNAM or ALTM, scenario chunks or unknown chunks and for .mif, soesn't uncompress TILE chunks.
  * Args:
  *      input_file (bytes): compressed city data.
  *      input_type (str): type of the input file we're opening.
  * Returns:
  *      A dictionary of uncompressed {chunk id: chunk data} form, one entry per chunk.
+ * Raises:
+ *      ParseError: a chunk the city parser relies on doesn't have the expected length.
  */
-pub fn sc2_uncompress_input(input_file: ChunkList, input_type: &str) -> ChunkList {
+pub fn sc2_uncompress_input(
+    input_file: ChunkList,
+    input_type: &str,
+) -> Result<ChunkList, ParseError> {
     let mut uncompressed_chunk_list = ChunkList::default();
 
     log::debug!("cnam: {}", input_file.cnam.len());
@@ -498,6 +623,9 @@ pub fn sc2_uncompress_input(input_file: ChunkList, input_type: &str) -> ChunkLis
             uncompressed_chunk_list.altm = input_file.altm;
             uncompressed_chunk_list.scen = input_file.scen;
             uncompressed_chunk_list.pict = input_file.pict;
+            uncompressed_chunk_list.unknown = input_file.unknown;
+
+            check_chunk_lengths(&uncompressed_chunk_list)?;
         }
 
         "mif" => {
@@ -506,10 +634,15 @@ pub fn sc2_uncompress_input(input_file: ChunkList, input_type: &str) -> ChunkLis
                 .map(|(key, slice)| (key, uncompress_rle(&slice)))
                 .for_each(|(key, value)| uncompressed_chunk_list.set(&key, &value));
 
+            // Tilesets store their images in chunks the city parser doesn't know, which are compressed as well.
+            for (key, slice) in &input_file.unknown {
+                uncompressed_chunk_list.set(key, &uncompress_rle(slice));
+            }
+
             uncompressed_chunk_list.tile = input_file.tile;
         }
 
-        _ => panic!("unknown input type: {}", input_type),
+        _ => return Err(ParseError::UnknownInputType(input_type.to_owned())),
     }
 
     log::debug!("cnam: {}", uncompressed_chunk_list.cnam.len(),);
@@ -518,13 +651,62 @@ pub fn sc2_uncompress_input(input_file: ChunkList, input_type: &str) -> ChunkLis
     log::debug!("xter: {}", uncompressed_chunk_list.xter.len(),);
     log::debug!("xbld: {}", uncompressed_chunk_list.xbld.len(),);
 
-    assert!(uncompressed_chunk_list.cnam.len() == 32);
-    assert!(uncompressed_chunk_list.misc.len() == 4800);
-    assert!(uncompressed_chunk_list.altm.len() == 32768);
-    assert!(uncompressed_chunk_list.xter.len() == 16384);
-    assert!(uncompressed_chunk_list.xbld.len() == 16384);
+    Ok(uncompressed_chunk_list)
+}
 
-    uncompressed_chunk_list
+/**
+ * Checks that all the fixed size chunks of an uncompressed city have the size the game writes them with.
+ * CNAM is allowed to be missing, as it is in some older cities.
+ * Args:
+ *      chunk_list (ChunkList): uncompressed city data.
+ * Raises:
+ *      ParseError: the first chunk that doesn't have the expected length.
+ */
+fn check_chunk_lengths(chunk_list: &ChunkList) -> Result<(), ParseError> {
+    let expected_lengths = [
+        ("MISC", 4800),
+        ("ALTM", 32768),
+        ("XTER", 16384),
+        ("XBLD", 16384),
+        ("XZON", 16384),
+        ("XUND", 16384),
+        ("XTXT", 16384),
+        ("XBIT", 16384),
+        ("XTRF", 4096),
+        ("XPLT", 4096),
+        ("XVAL", 4096),
+        ("XCRM", 4096),
+        ("XPLC", 1024),
+        ("XFIR", 1024),
+        ("XPOP", 1024),
+        ("XROG", 1024),
+        ("XGRP", 3328),
+    ];
+
+    if !chunk_list.cnam.is_empty() && chunk_list.cnam.len() != 32 {
+        return Err(ParseError::UnexpectedChunkLength {
+            chunk_id: String::from("CNAM"),
+            expected: 32,
+            actual: chunk_list.cnam.len(),
+        });
+    }
+
+    for (chunk_id, expected) in expected_lengths {
+        let actual = chunk_list
+            .get(chunk_id)
+            .map(<[u8]>::len)
+            .unwrap_or_default();
+
+        if actual != expected {
+            return Err(ParseError::UnexpectedChunkLength {
+                chunk_id: chunk_id.to_owned(),
+                expected,
+                actual,
+            });
+        }
+    }
+
+    Ok(())
 }
 
 /**
@@ -534,7 +716,7 @@ pub fn sc2_uncompress_input(input_file: ChunkList, input_type: &str) -> ChunkLis
  * Returns:
  *      Uncompressed bytes.
  */
-fn uncompress_rle(encoded_data: &[u8]) -> Vec<u8> {
+pub fn uncompress_rle(encoded_data: &[u8]) -> Vec<u8> {
     let mut decoded_data = vec![];
     let mut next_byte_repeat = false;
     let mut byte_count = 0u8;
@@ -583,3 +765,219 @@ fn uncompress_rle(encoded_data: &[u8]) -> Vec<u8> {
 
     decoded_data
 }
+
+/**
+ * Compresses uncompressed city data so it can be written into a .sc2 file.
+ * This is the inverse of sc2_uncompress_input(), CNAM, ALTM, scenario and unknown chunks are left as they are.
+ * Args:
+ *      input_file (ChunkList): uncompressed city data.
+ * Returns:
+ *      A list of compressed chunks, one entry per chunk.
+ */
+pub fn sc2_compress_output(input_file: ChunkList) -> ChunkList {
+    let mut compressed_chunk_list = ChunkList::default();
+
+    log::debug!("compressing file data...");
+
+    input_file
+        .iter_compressed()
+        .map(|(key, slice)| {
+            log::debug!("compressing {}...", key);
+
+            (key, compress_rle(&slice))
+        })
+        .for_each(|(key, value)| compressed_chunk_list.set(&key, &value));
+
+    compressed_chunk_list.text = input_file.text;
+    compressed_chunk_list.cnam = input_file.cnam;
+    compressed_chunk_list.altm = input_file.altm;
+    compressed_chunk_list.scen = input_file.scen;
+    compressed_chunk_list.pict = input_file.pict;
+    compressed_chunk_list.unknown = input_file.unknown;
+
+    compressed_chunk_list
+}
+
+/**
+ * Takes already compressed city data and serializes it into a FORM/SCDH IFF container.
+ * Chunks are written in the same order the game uses, empty chunks are skipped.
+ * Args:
+ *      chunk_list (ChunkList): compressed city data.
+ * Returns:
+ *      Bytes of a complete .sc2 file.
+ */
+pub fn chunk_output_serial(chunk_list: &ChunkList) -> Vec<u8> {
+    let mut body = vec![];
+
+    body.extend_from_slice(b"SCDH");
+
+    for (chunk_id, chunk_data) in chunk_list.chunks() {
+        log::debug!("writing chunk {} with {}B...", chunk_id, chunk_data.len());
+
+        body.extend_from_slice(chunk_id.as_bytes());
+        body.extend_from_slice(&(chunk_data.len() as u32).to_be_bytes());
+        body.extend_from_slice(chunk_data);
+    }
+
+    // The reported size doesn't include the FORM header and the size itself.
+    let mut output = Vec::with_capacity(body.len() + 8);
+
+    output.extend_from_slice(b"FORM");
+    output.extend_from_slice(&(body.len() as u32).to_be_bytes());
+    output.append(&mut body);
+
+    output
+}
+
+/**
+ * RLE compresses city data. For more information, consult the .sc2 file format specification documents at https://github.com/dfloer/SC2k-docs
+ * This is the inverse of uncompress_rle(). Runs of 3 or more identical bytes are stored as repeats, everything else as literal data.
+ * Args:
+ *      decoded_data (bytes): uncompressed city information.
+ * Returns:
+ *      Compressed bytes.
+ */
+pub fn compress_rle(decoded_data: &[u8]) -> Vec<u8> {
+    let mut encoded_data = vec![];
+    let mut literal_start = 0;
+    let mut cursor = 0;
+
+    // Data is stored in two forms: 0x01..0x7F and 0x81..0xFF
+    while cursor < decoded_data.len() {
+        let byte = decoded_data[cursor];
+        let run_length = decoded_data[cursor..]
+            .iter()
+            .take(0x80)
+            .take_while(|next| **next == byte)
+            .count();
+
+        if run_length < 3 {
+            cursor += run_length;
+            continue;
+        }
+
+        append_rle_literals(&mut encoded_data, &decoded_data[literal_start..cursor]);
+
+        // In this case, count+127 is followed by the byte that repeats.
+        encoded_data.push(run_length as u8 + 0x7F);
+        encoded_data.push(byte);
+        log::debug!("repeated byte {:#04x} x {}", byte, run_length);
+
+        cursor += run_length;
+        literal_start = cursor;
+    }
+
+    append_rle_literals(&mut encoded_data, &decoded_data[literal_start..]);
+
+    encoded_data
+}
+
+/**
+ * Appends literal data to RLE compressed data, split into runs of at most 0x7F bytes.
+ * Args:
+ *      encoded_data (bytes): compressed data to append to.
+ *      literals (bytes): uncompressed bytes that are stored as they are.
+ */
+fn append_rle_literals(encoded_data: &mut Vec<u8>, literals: &[u8]) {
+    for run in literals.chunks(0x7F) {
+        // In this case, count is followed by count data bytes.
+        encoded_data.push(run.len() as u8);
+        encoded_data.extend_from_slice(run);
+        log::debug!("appending {} literal bytes", run.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_rng::Rng;
+    use super::*;
+
+    fn round_trip(decoded_data: &[u8]) {
+        let encoded_data = compress_rle(decoded_data);
+        let mut cursor = 0;
+
+        // The game only uses counts of 0x01..0x7F and 0x81..0xFF.
+        while cursor < encoded_data.len() {
+            let count = encoded_data[cursor];
+
+            assert!(
+                count != 0x00 && count != 0x80,
+                "count {count:#04x} at {cursor}"
+            );
+            cursor += if count < 0x80 { 1 + count as usize } else { 2 };
+        }
+
+        assert_eq!(cursor, encoded_data.len());
+        assert_eq!(uncompress_rle(&encoded_data), decoded_data);
+    }
+
+    #[test]
+    fn rle_round_trip() {
+        round_trip(&[]);
+        round_trip(&[0x42]);
+
+        for run_length in [2, 3, 128, 129] {
+            round_trip(&vec![0x42; run_length]);
+        }
+
+        // Literals, a repeat, literals again and a run long enough to be split.
+        let mut mixed = vec![1, 2, 3];
+        mixed.extend([7; 5]);
+        mixed.extend([4, 5, 5, 6]);
+        mixed.extend([9; 200]);
+        mixed.extend(0..=255);
+        round_trip(&mixed);
+    }
+
+    #[test]
+    fn rle_run_lengths() {
+        // Runs shorter than 3 are stored as literals, longer runs are split at 128.
+        assert_eq!(compress_rle(&[0x42; 2]), [0x02, 0x42, 0x42]);
+        assert_eq!(compress_rle(&[0x42; 3]), [0x82, 0x42]);
+        assert_eq!(compress_rle(&[0x42; 128]), [0xFF, 0x42]);
+        assert_eq!(compress_rle(&[0x42; 129]), [0xFF, 0x42, 0x01, 0x42]);
+    }
+
+    #[test]
+    fn rle_game_encoding() {
+        // Hand-built chunk in the game's encoding: 3 literals, 5 zeros, 127 literals, 128 repeats and a single literal.
+        let mut encoded_data = vec![0x03, 0x10, 0x20, 0x30, 0x84, 0x00, 0x7F];
+        encoded_data.extend(1..=127);
+        encoded_data.extend([0xFF, 0xAA, 0x01, 0xBB]);
+
+        let mut decoded_data = vec![0x10, 0x20, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00];
+        decoded_data.extend(1..=127);
+        decoded_data.extend([0xAA; 128]);
+        decoded_data.push(0xBB);
+
+        assert_eq!(uncompress_rle(&encoded_data), decoded_data);
+        assert_eq!(compress_rle(&decoded_data), encoded_data);
+    }
+
+    #[test]
+    fn rle_random_round_trip() {
+        let mut rng = Rng(0x0DF1_0E12);
+        // Lengths around the limits of a literal (127) and a repeat (128) run.
+        let run_lengths = [1, 2, 3, 4, 126, 127, 128, 129, 130, 255, 256];
+
+        for _ in 0..2000 {
+            let mut decoded_data = vec![];
+
+            for _ in 0..rng.below(8) {
+                let run_length = match rng.below(3) {
+                    0 => run_lengths[rng.below(run_lengths.len())],
+                    _ => rng.below(300),
+                };
+
+                // Either a run of one byte, or bytes from a small alphabet so short repeats show up by chance.
+                if rng.below(2) == 0 {
+                    decoded_data.extend(vec![rng.next() as u8; run_length]);
+                } else {
+                    decoded_data.extend((0..run_length).map(|_| rng.below(3) as u8));
+                }
+            }
+
+            round_trip(&decoded_data);
+        }
+    }
+}
@@ -1,8 +1,7 @@
 use std::fs::File;
-use std::io::Read;
+use std::io::{self, Read};
 use std::path::Path;
 
-use anyhow::Context;
 /**
  * Parses 4 bytes into a big endian unsigned integer.
  * Args:
@@ -25,11 +24,7 @@ pub fn parse_uint32(unparsed_bytes: &[u8; 4]) -> u32 {
 pub fn parse_uint16(unparsed_bytes: &[u8; 2]) -> u16 {
     assert!(unparsed_bytes.len() == 2);
 
-    let result = u16::from_be_bytes(*unparsed_bytes);
-
-    assert!(result > 0);
-
-    result
+    u16::from_be_bytes(*unparsed_bytes)
 }
 
 /** Parses 1 byte into a big endian signed integer.
@@ -54,70 +49,19 @@ pub fn parse_int32(unparsed_bytes: &[u8; 4]) -> i32 {
     i32::from_be_bytes(*unparsed_bytes)
 }
 
-/** Converts an int into its binary representation as a string of 0s and 1s.
- * Optionally takes an argument for the length to pad to.
- * Args:
- *      int_input (int): integer to convert.
- *      pad (int): Pad with 0s to this length.
- * Returns:
- *      String representation of the input integer in binary.
- */
-pub fn int_to_bitstring(int: u32, pad: usize) -> String {
-    let mut result = String::from("");
-    let mut int_input = int;
-
-    while int_input > 0 {
-        let bit = int_input & 1;
-
-        result = bit.to_string() + &result;
-        int_input >>= 1;
-    }
-
-    while result.len() < pad {
-        result = String::from("0") + &result;
-    }
-
-    result
-}
-
-pub fn parse_bitstring(bit_string: &str) -> u32 {
-    let mut result: u32 = 0;
-
-    for bit in bit_string.chars() {
-        if result == 0 && bit == '0' {
-            continue;
-        }
-
-        result = (result << 1)
-            | u32::from_str_radix(&bit.to_string(), 2)
-                .expect("bit_string can only contain 0 and 1");
-    }
-
-    result
-}
-
 /** Convenience function that opens a file and returns its binary contents.
  * Args:
  *      input_file (path): full path of a file to open.
  * Returns:
  *      Raw binary contents of the input file.
  */
-pub fn open_file(input_file: &Path) -> Vec<u8> {
-    let mut f = File::open(input_file)
-        .with_context(|| {
-            format!(
-                "file path {} does not exists or can't be opened!",
-                input_file.to_str().unwrap_or_default()
-            )
-        })
-        .unwrap_or_else(|err| panic!("{:?}", err));
-
+pub fn open_file(input_file: &Path) -> io::Result<Vec<u8>> {
+    let mut f = File::open(input_file)?;
     let mut buffer = vec![];
 
-    f.read_to_end(&mut buffer)
-        .expect("failed to read file contents");
+    f.read_to_end(&mut buffer)?;
 
-    buffer
+    Ok(buffer)
 }
 
 /** Turns a sequence of bytes (must be a multiple of 4) into a list of integers.
@@ -142,6 +86,29 @@ pub fn bytes_to_int32s(input_bytes: &[u8]) -> Vec<i32> {
     res
 }
 
+/** Turns a list of integers into a sequence of bytes, the inverse of bytes_to_int32s().
+ * Args:
+ *      input_ints (list): signed integers to convert
+ * Returns:
+ *      Big endian bytes, 4 per integer.
+ */
+pub fn int32s_to_bytes(input_ints: &[i32]) -> Vec<u8> {
+    input_ints
+        .iter()
+        .flat_map(|int| int.to_be_bytes())
+        .collect()
+}
+
+/** Writes a big endian signed integer into a buffer at the given offset.
+ * Args:
+ *      target (bytes): buffer to write into.
+ *      offset (int): offset of the first of the 4 bytes.
+ *      value (int): integer to write.
+ */
+pub fn write_int32(target: &mut [u8], offset: usize, value: i32) {
+    target[offset..(offset + 4)].copy_from_slice(&value.to_be_bytes());
+}
+
 /**
  * Variable length convenience function to convert some number of bytes to an int.
  * Args:
@@ -0,0 +1,104 @@
+// Decodes the XTER terrain byte.
+// The high nibble describes the water on the tile, the low nibble the slope of the ground.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Shape of the ground of a tile, named after the raised sides or corners.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Slope {
+    Flat,
+    /// The north edge is raised.
+    North,
+    /// The east edge is raised.
+    East,
+    /// The south edge is raised.
+    South,
+    /// The west edge is raised.
+    West,
+    /// The north and east edges are raised, only the south west corner is low.
+    NorthEast,
+    /// The south and east edges are raised, only the north west corner is low.
+    SouthEast,
+    /// The south and west edges are raised, only the north east corner is low.
+    SouthWest,
+    /// The north and west edges are raised, only the south east corner is low.
+    NorthWest,
+    /// Only the north east corner is raised.
+    CornerNorthEast,
+    /// Only the south east corner is raised.
+    CornerSouthEast,
+    /// Only the south west corner is raised.
+    CornerSouthWest,
+    /// Only the north west corner is raised.
+    CornerNorthWest,
+    /// The whole tile is raised by one level.
+    Highground,
+}
+
+/// Water on a tile.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum WaterKind {
+    Dry,
+    /// Ground below the water surface.
+    Submerged,
+    /// Ground that's partially covered by water.
+    Shore,
+    /// Water surface.
+    Surface,
+    Waterfall,
+    /// Stream or canal, always flat.
+    Canal,
+}
+
+/// Decoded XTER value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Terrain {
+    pub slope: Slope,
+    pub water: WaterKind,
+}
+
+const SLOPES: [Slope; 14] = [
+    Slope::Flat,
+    Slope::North,
+    Slope::East,
+    Slope::South,
+    Slope::West,
+    Slope::NorthEast,
+    Slope::SouthEast,
+    Slope::SouthWest,
+    Slope::NorthWest,
+    Slope::CornerNorthEast,
+    Slope::CornerSouthEast,
+    Slope::CornerSouthWest,
+    Slope::CornerNorthWest,
+    Slope::Highground,
+];
+
+impl Terrain {
+    /**
+     * Decodes an XTER byte.
+     * Args:
+     *      xter (int): raw terrain byte.
+     * Returns:
+     *      The terrain, or None if the byte isn't used by the game.
+     */
+    pub fn from_byte(xter: u8) -> Option<Self> {
+        let water = match xter >> 4 {
+            0x0 => WaterKind::Dry,
+            0x1 => WaterKind::Submerged,
+            0x2 => WaterKind::Shore,
+            0x3 if xter == 0x3E => WaterKind::Waterfall,
+            0x3 => WaterKind::Surface,
+            0x4 if xter <= 0x45 => WaterKind::Canal,
+            _ => return None,
+        };
+
+        let slope = match water {
+            WaterKind::Waterfall | WaterKind::Canal => Slope::Flat,
+            _ => *SLOPES.get(usize::from(xter & 0x0F))?,
+        };
+
+        Some(Self { slope, water })
+    }
+}
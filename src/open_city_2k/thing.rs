@@ -1,9 +1,10 @@
 use std::fmt::Display;
 
-use serde::Serialize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 /// Class to represent a thing stored in the XTHG segment.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct Thing {
     thing_id: usize,
     rotation_1: usize,
@@ -38,6 +39,40 @@ impl Thing {
             data,
         }
     }
+
+    pub fn thing_id(&self) -> usize {
+        self.thing_id
+    }
+
+    pub fn rotations(&self) -> (usize, usize) {
+        (self.rotation_1, self.rotation_2)
+    }
+
+    pub fn coordinates(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    pub fn data(&self) -> &[u8; 7] {
+        &self.data
+    }
+
+    /**
+     * Converts the thing back into its raw representation.
+     * Returns:
+     *      12 bytes representing the thing.
+     */
+    pub fn to_bytes(&self) -> [u8; 12] {
+        let mut raw_thing = [0u8; 12];
+
+        raw_thing[0] = self.thing_id as u8;
+        raw_thing[1] = self.rotation_1 as u8;
+        raw_thing[2] = self.rotation_2 as u8;
+        raw_thing[3] = self.x as u8;
+        raw_thing[4] = self.y as u8;
+        raw_thing[5..12].copy_from_slice(&self.data);
+
+        raw_thing
+    }
 }
 
 impl Display for Thing {
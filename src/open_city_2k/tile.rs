@@ -2,31 +2,50 @@
 
 use super::bit_flags::BitFlags;
 use super::building::Building;
+use super::terrain::Terrain;
+use super::underground::Underground;
+use super::zone::{Corners, Zone};
 use super::City;
-use serde::Serialize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use std::ops::Deref;
 use std::sync::Arc;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct Tile {
     coordinates: (usize, usize),
     altitude_tunnel: u32,
     is_water: bool,
     altitude_unknown: u32,
     altitude: u32,
+    /// Raw XTER value.
     terrain: u8,
+    /// Slope and water decoded from XTER, missing if the game doesn't use the raw value.
+    #[serde(default)]
+    decoded_terrain: Option<Terrain>,
+    /// Raw XBLD value, written back as it was read.
+    building_id: u8,
     building: Option<Arc<Building>>,
-    zone_corners: String,
-    zone: u32,
+    zone_corners: Corners,
+    zone: Zone,
+    /// Raw XUND value.
     underground: u8,
-    label: Vec<String>,
+    /// Pipes, subways and tunnels decoded from XUND, missing if the game doesn't use the raw value.
+    #[serde(default)]
+    decoded_underground: Option<Underground>,
+    /// Raw XTXT value, an index into the labels of the city if the tile has a sign.
     text_pointer: i32,
     bit_flags: Option<BitFlags>,
 }
 
+impl Default for Tile {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Tile {
-    #[allow(clippy::too_many_arguments)]
-    pub fn new(label: Vec<String>) -> Self {
+    pub fn new() -> Self {
         let coordinates = (0, 0);
         // Altitude map related values.
         let altitude_tunnel = 0;
@@ -35,11 +54,14 @@ impl Tile {
         let altitude = 0;
         // Terrain
         let terrain = 0;
+        let decoded_terrain = Terrain::from_byte(terrain);
         // City stuff
+        let building_id = 0;
         let building = None;
-        let zone_corners = String::from("");
-        let zone = 0;
+        let zone_corners = Corners::NONE;
+        let zone = Zone::None;
         let underground = 0;
+        let decoded_underground = Underground::from_byte(underground);
         // text/signs
         let text_pointer = -1;
         // bit flags
@@ -53,11 +75,13 @@ impl Tile {
             altitude_unknown,
             altitude,
             terrain,
+            decoded_terrain,
+            building_id,
             building,
             zone_corners,
             zone,
             underground,
-            label,
+            decoded_underground,
             text_pointer,
             bit_flags,
         }
@@ -109,21 +133,26 @@ impl Tile {
 
     pub fn set_terrain(&mut self, value: u8) {
         self.terrain = value;
+        self.decoded_terrain = Terrain::from_byte(value);
     }
 
-    pub fn zone_corners(&self) -> &str {
-        &self.zone_corners
+    pub fn decoded_terrain(&self) -> Option<Terrain> {
+        self.decoded_terrain
     }
 
-    pub fn set_zone_corners(&mut self, value: String) {
+    pub fn zone_corners(&self) -> Corners {
+        self.zone_corners
+    }
+
+    pub fn set_zone_corners(&mut self, value: Corners) {
         self.zone_corners = value;
     }
 
-    pub fn zone(&self) -> &u32 {
-        &self.zone
+    pub fn zone(&self) -> Zone {
+        self.zone
     }
 
-    pub fn set_zone(&mut self, value: u32) {
+    pub fn set_zone(&mut self, value: Zone) {
         self.zone = value;
     }
 
@@ -133,6 +162,11 @@ impl Tile {
 
     pub fn set_underground(&mut self, value: u8) {
         self.underground = value;
+        self.decoded_underground = Underground::from_byte(value);
+    }
+
+    pub fn decoded_underground(&self) -> Option<Underground> {
+        self.decoded_underground
     }
 
     pub fn text_pointer(&self) -> i32 {
@@ -183,11 +217,24 @@ impl Tile {
         *city.growth.get_scaled(self.coordinates)
     }
 
-    pub fn text(&self) -> Option<&str> {
-        self.label.get(self.text_pointer as usize).map(Deref::deref)
+    /// Text of the tile's sign, looked up in the labels of the city.
+    pub fn text<'c>(&self, city: &'c City) -> Option<&'c str> {
+        // Slot 0 holds the mayor's name, so a text pointer of 0 means there's no sign.
+        let label_id = usize::try_from(self.text_pointer)
+            .ok()
+            .filter(|label_id| *label_id != 0)?;
+
+        city.labels().get(label_id).map(Deref::deref)
+    }
+
+    pub fn building_id(&self) -> u8 {
+        self.building_id
+    }
+
+    pub fn set_building_id(&mut self, value: u8) {
+        self.building_id = value;
     }
 
-    #[allow(dead_code)]
     pub fn building(&self) -> &Option<Arc<Building>> {
         &self.building
     }
@@ -0,0 +1,58 @@
+// Columnar view of the tilelist, one plane per tile attribute.
+
+use schemars::{schema::RootSchema, schema_for, JsonSchema};
+use serde::{Deserialize, Serialize};
+
+use super::grid::Grid;
+
+/// One city_size x city_size plane per bit of the XBIT segment.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BitFlagLayers {
+    pub powerable: Grid<bool>,
+    pub powered: Grid<bool>,
+    pub piped: Grid<bool>,
+    pub watered: Grid<bool>,
+    pub xval: Grid<bool>,
+    pub water: Grid<bool>,
+    pub rotate: Grid<bool>,
+    pub salt: Grid<bool>,
+}
+
+/// The tilelist split into row-major planes of city_size x city_size cells, ready to be loaded as textures or arrays.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TileLayers {
+    /// Version of this layout, shared with the City layout.
+    pub format_version: u32,
+    /// Width and height of every plane.
+    pub city_size: usize,
+    /// Altitude from ALTM, 0 to 31.
+    pub altitude: Grid<u8>,
+    /// Water flag from ALTM.
+    pub water: Grid<bool>,
+    /// Raw XTER value, not decoded. The high nibble is the water and the low nibble the slope, Terrain::from_byte
+    /// decodes it.
+    pub terrain_raw: Grid<u8>,
+    /// Zone from the low nibble of XZON, see Zone for the values.
+    pub zone: Grid<u8>,
+    /// Building corners from the high nibble of XZON, see Corners for the bits.
+    pub zone_corners: Grid<u8>,
+    /// Raw XUND value, see Underground for the values.
+    pub underground: Grid<u8>,
+    /// Id of the building on the tile, 0 if the tile is empty.
+    pub building_id: Grid<u8>,
+    /// Index of the tile's sign into the labels.
+    pub text_pointer: Grid<u8>,
+    /// Bits from XBIT.
+    pub bit_flags: BitFlagLayers,
+}
+
+impl TileLayers {
+    /**
+     * Generates the JSON Schema of serialized tile layers.
+     * Returns:
+     *      Schema describing the JSON output of the layers export.
+     */
+    pub fn json_schema() -> RootSchema {
+        schema_for!(TileLayers)
+    }
+}
@@ -0,0 +1,321 @@
+// Decodes the XUND underground byte and builds the pipe and subway networks from it.
+
+use std::collections::{HashMap, HashSet};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::grid::Grid;
+
+/// Side of a tile. Top and Bottom face the previous and next row, Left and Right the previous and next column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Side {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl Side {
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Right => Self::Left,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+        }
+    }
+}
+
+const ALL_SIDES: [Side; 4] = [Side::Top, Side::Right, Side::Bottom, Side::Left];
+
+/// Shape of a subway or pipe piece. The pieces are stored in the same order as the road pieces in XBLD.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum NetworkPiece {
+    LeftRight,
+    TopBottom,
+    HighTopBottom,
+    LeftHighRight,
+    TopHighBottom,
+    HighLeftRight,
+    BottomRight,
+    BottomLeft,
+    TopLeft,
+    TopRight,
+    RightTopLeft,
+    RightBottomLeft,
+    TopLeftBottom,
+    /// Named like the road piece, but it's the T junction open to the top, right and bottom.
+    LeftTopBottom,
+    LeftTopBottomRight,
+}
+
+impl NetworkPiece {
+    /// Sides the piece connects to. Slopes don't change the direction of a piece.
+    pub fn openings(self) -> &'static [Side] {
+        match self {
+            Self::LeftRight | Self::LeftHighRight | Self::HighLeftRight => {
+                &[Side::Left, Side::Right]
+            }
+            Self::TopBottom | Self::HighTopBottom | Self::TopHighBottom => {
+                &[Side::Top, Side::Bottom]
+            }
+            Self::BottomRight => &[Side::Bottom, Side::Right],
+            Self::BottomLeft => &[Side::Bottom, Side::Left],
+            Self::TopLeft => &[Side::Top, Side::Left],
+            Self::TopRight => &[Side::Top, Side::Right],
+            Self::RightTopLeft => &[Side::Right, Side::Top, Side::Left],
+            Self::RightBottomLeft => &[Side::Right, Side::Bottom, Side::Left],
+            Self::TopLeftBottom => &[Side::Top, Side::Left, Side::Bottom],
+            Self::LeftTopBottom => &[Side::Top, Side::Right, Side::Bottom],
+            Self::LeftTopBottomRight => &ALL_SIDES,
+        }
+    }
+}
+
+const NETWORK_PIECES: [NetworkPiece; 15] = [
+    NetworkPiece::LeftRight,
+    NetworkPiece::TopBottom,
+    NetworkPiece::HighTopBottom,
+    NetworkPiece::LeftHighRight,
+    NetworkPiece::TopHighBottom,
+    NetworkPiece::HighLeftRight,
+    NetworkPiece::BottomRight,
+    NetworkPiece::BottomLeft,
+    NetworkPiece::TopLeft,
+    NetworkPiece::TopRight,
+    NetworkPiece::RightTopLeft,
+    NetworkPiece::RightBottomLeft,
+    NetworkPiece::TopLeftBottom,
+    NetworkPiece::LeftTopBottom,
+    NetworkPiece::LeftTopBottomRight,
+];
+
+/// Decoded XUND value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Underground {
+    Empty,
+    /// 0x01 - 0x0F
+    Subway(NetworkPiece),
+    /// 0x10 - 0x1E
+    Pipe(NetworkPiece),
+    /// 0x1F - 0x20, a subway crossing a pipe. The pipe runs across the subway.
+    SubwayPipeCrossing {
+        subway: NetworkPiece,
+    },
+    /// 0x21, a road or rail tunnel. Its depth is stored in ALTM.
+    Tunnel,
+    /// 0x22, the underground part of missile silos and desalinization plants.
+    MissileSilo,
+    /// 0x23, subway stations and subway to rail connections.
+    SubwayStation,
+}
+
+impl Underground {
+    /**
+     * Decodes an XUND byte.
+     * Args:
+     *      xund (int): raw underground byte.
+     * Returns:
+     *      The underground contents, or None if the byte isn't used by the game.
+     */
+    pub fn from_byte(xund: u8) -> Option<Self> {
+        let underground = match xund {
+            0x00 => Self::Empty,
+            0x01..=0x0F => Self::Subway(NETWORK_PIECES[usize::from(xund - 0x01)]),
+            0x10..=0x1E => Self::Pipe(NETWORK_PIECES[usize::from(xund - 0x10)]),
+            0x1F => Self::SubwayPipeCrossing {
+                subway: NetworkPiece::LeftRight,
+            },
+            0x20 => Self::SubwayPipeCrossing {
+                subway: NetworkPiece::TopBottom,
+            },
+            0x21 => Self::Tunnel,
+            0x22 => Self::MissileSilo,
+            0x23 => Self::SubwayStation,
+            _ => return None,
+        };
+
+        Some(underground)
+    }
+
+    /// Returns true if the tile is part of the pipe network.
+    pub fn has_pipe(&self) -> bool {
+        matches!(self, Self::Pipe(_) | Self::SubwayPipeCrossing { .. })
+    }
+
+    /// Returns true if the tile is part of the subway network.
+    pub fn has_subway(&self) -> bool {
+        matches!(
+            self,
+            Self::Subway(_) | Self::SubwayPipeCrossing { .. } | Self::SubwayStation
+        )
+    }
+
+    /// Sides the pipe on this tile connects to, None if there's no pipe.
+    pub fn pipe_openings(&self) -> Option<&'static [Side]> {
+        match self {
+            Self::Pipe(piece) => Some(piece.openings()),
+            Self::SubwayPipeCrossing {
+                subway: NetworkPiece::LeftRight,
+            } => Some(NetworkPiece::TopBottom.openings()),
+            Self::SubwayPipeCrossing { .. } => Some(NetworkPiece::LeftRight.openings()),
+            _ => None,
+        }
+    }
+
+    /// Sides the subway on this tile connects to, None if there's no subway. Stations connect to every side.
+    pub fn subway_openings(&self) -> Option<&'static [Side]> {
+        match self {
+            Self::Subway(piece) | Self::SubwayPipeCrossing { subway: piece } => {
+                Some(piece.openings())
+            }
+            Self::SubwayStation => Some(&ALL_SIDES),
+            _ => None,
+        }
+    }
+}
+
+/// Graph of the tiles that make up an underground network. Two tiles that share an edge are connected if both
+/// pieces are open towards each other.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct UndergroundNetwork {
+    /// Coordinates of all tiles in the network, in row-major order.
+    tiles: Vec<(usize, usize)>,
+    /// Pairs of connected tiles.
+    connections: Vec<[(usize, usize); 2]>,
+}
+
+impl UndergroundNetwork {
+    /**
+     * Builds the network from the tiles that carry it.
+     * Args:
+     *      underground: decoded XUND values of the whole map.
+     *      openings: sides a tile connects to, None if it isn't part of the network.
+     */
+    pub fn from_grid(
+        underground: &Grid<Option<Underground>>,
+        openings: impl Fn(&Underground) -> Option<&'static [Side]>,
+    ) -> Self {
+        let opens_to = |coords, side| {
+            underground
+                .get(coords)
+                .copied()
+                .flatten()
+                .and_then(|tile| openings(&tile))
+                .is_some_and(|sides| sides.contains(&side))
+        };
+
+        let tiles: Vec<(usize, usize)> = underground
+            .iter()
+            .filter(|(_, tile)| tile.is_some_and(|tile| openings(&tile).is_some()))
+            .map(|(coords, _)| coords)
+            .collect();
+
+        let mut connections = vec![];
+
+        for &(row, col) in &tiles {
+            for (neighbour, side) in [
+                ((row + 1, col), Side::Bottom),
+                ((row, col + 1), Side::Right),
+            ] {
+                if opens_to((row, col), side) && opens_to(neighbour, side.opposite()) {
+                    connections.push([(row, col), neighbour]);
+                }
+            }
+        }
+
+        Self { tiles, connections }
+    }
+
+    pub fn tiles(&self) -> &[(usize, usize)] {
+        &self.tiles
+    }
+
+    pub fn connections(&self) -> &[[(usize, usize); 2]] {
+        &self.connections
+    }
+
+    /**
+     * Splits the network into its separate parts.
+     * Returns:
+     *      The tiles of every connected part of the network.
+     */
+    pub fn components(&self) -> Vec<Vec<(usize, usize)>> {
+        let mut neighbours: HashMap<(usize, usize), Vec<(usize, usize)>> = HashMap::new();
+
+        for [from, to] in &self.connections {
+            neighbours.entry(*from).or_default().push(*to);
+            neighbours.entry(*to).or_default().push(*from);
+        }
+
+        let mut visited = HashSet::new();
+        let mut components = vec![];
+
+        for &start in &self.tiles {
+            if !visited.insert(start) {
+                continue;
+            }
+
+            let mut component = vec![];
+            let mut stack = vec![start];
+
+            while let Some(tile) = stack.pop() {
+                component.push(tile);
+
+                for &next in neighbours.get(&tile).into_iter().flatten() {
+                    if visited.insert(next) {
+                        stack.push(next);
+                    }
+                }
+            }
+
+            components.push(component);
+        }
+
+        components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two pipe runs along rows 0 and 1, four tiles each.
+    fn parallel_pipes() -> Grid<Option<Underground>> {
+        let mut underground = Grid::new(6, 6);
+
+        for row in 0..2 {
+            for col in 0..4 {
+                underground[(row, col)] = Some(Underground::Pipe(NetworkPiece::LeftRight));
+            }
+        }
+
+        underground
+    }
+
+    #[test]
+    fn parallel_runs_stay_apart() {
+        let network = UndergroundNetwork::from_grid(&parallel_pipes(), Underground::pipe_openings);
+
+        assert_eq!(network.tiles().len(), 8);
+        assert_eq!(network.connections().len(), 6);
+        assert!(network
+            .connections()
+            .iter()
+            .all(|[from, to]| from.0 == to.0));
+        assert_eq!(network.components().len(), 2);
+    }
+
+    #[test]
+    fn corners_join_runs() {
+        let mut underground = parallel_pipes();
+        underground[(0, 3)] = Some(Underground::Pipe(NetworkPiece::BottomLeft));
+        underground[(1, 3)] = Some(Underground::Pipe(NetworkPiece::TopLeft));
+
+        let network = UndergroundNetwork::from_grid(&underground, Underground::pipe_openings);
+
+        assert_eq!(network.connections().len(), 7);
+        assert_eq!(network.components().len(), 1);
+    }
+}
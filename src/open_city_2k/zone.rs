@@ -0,0 +1,124 @@
+// Decodes the XZON byte: the low nibble stores the zone, the high nibble the corners of the building on the tile.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Zone of a tile.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Zone {
+    #[default]
+    None,
+    LightResidential,
+    DenseResidential,
+    LightCommercial,
+    DenseCommercial,
+    LightIndustrial,
+    DenseIndustrial,
+    Military,
+    Airport,
+    Seaport,
+    /// A value the game doesn't use, kept so it is written back unchanged.
+    Unknown(u8),
+}
+
+impl Zone {
+    /**
+     * Decodes the zone from the low nibble of XZON.
+     * Args:
+     *      xzon (int): raw XZON byte.
+     */
+    pub fn from_xzon(xzon: u8) -> Self {
+        match xzon & 0x0F {
+            0 => Self::None,
+            1 => Self::LightResidential,
+            2 => Self::DenseResidential,
+            3 => Self::LightCommercial,
+            4 => Self::DenseCommercial,
+            5 => Self::LightIndustrial,
+            6 => Self::DenseIndustrial,
+            7 => Self::Military,
+            8 => Self::Airport,
+            9 => Self::Seaport,
+            value => Self::Unknown(value),
+        }
+    }
+
+    /// The zone as stored in the low nibble of XZON.
+    pub fn to_u8(self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::LightResidential => 1,
+            Self::DenseResidential => 2,
+            Self::LightCommercial => 3,
+            Self::DenseCommercial => 4,
+            Self::LightIndustrial => 5,
+            Self::DenseIndustrial => 6,
+            Self::Military => 7,
+            Self::Airport => 8,
+            Self::Seaport => 9,
+            Self::Unknown(value) => value & 0x0F,
+        }
+    }
+}
+
+/// Corners of the building that lie on a tile in the unrotated city. The highest bit is the left corner, the
+/// remaining bits follow clockwise.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Corners {
+    left: bool,
+    top: bool,
+    right: bool,
+    bottom: bool,
+}
+
+impl Corners {
+    pub const NONE: Self = Self::from_bits(0b0000);
+    pub const LEFT: Self = Self::from_bits(0b1000);
+    pub const TOP: Self = Self::from_bits(0b0100);
+    pub const RIGHT: Self = Self::from_bits(0b0010);
+    pub const BOTTOM: Self = Self::from_bits(0b0001);
+
+    /**
+     * Creates corners from their bits.
+     * Args:
+     *      bits (int): corners as stored in the high nibble of XZON, left is the highest bit.
+     */
+    pub const fn from_bits(bits: u8) -> Self {
+        Self {
+            left: bits & 0b1000 != 0,
+            top: bits & 0b0100 != 0,
+            right: bits & 0b0010 != 0,
+            bottom: bits & 0b0001 != 0,
+        }
+    }
+
+    /// Decodes the corners from the high nibble of XZON.
+    pub fn from_xzon(xzon: u8) -> Self {
+        Self::from_bits(xzon >> 4)
+    }
+
+    pub fn bits(&self) -> u8 {
+        (self.left as u8) << 3 | (self.top as u8) << 2 | (self.right as u8) << 1 | self.bottom as u8
+    }
+
+    /// Returns true if all corners of other are set.
+    pub fn contains(&self, other: Self) -> bool {
+        self.bits() & other.bits() == other.bits()
+    }
+
+    pub fn left(&self) -> bool {
+        self.left
+    }
+
+    pub fn top(&self) -> bool {
+        self.top
+    }
+
+    pub fn right(&self) -> bool {
+        self.right
+    }
+
+    pub fn bottom(&self) -> bool {
+        self.bottom
+    }
+}